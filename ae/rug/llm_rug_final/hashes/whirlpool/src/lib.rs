@@ -57,10 +57,10 @@ use digest::{
     block_buffer::Eager,
     core_api::{
         AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, FixedOutputCore,
-        OutputSizeUser, Reset, UpdateCore,
+        OutputSizeUser, Reset, RtVariableCoreWrapper, TruncSide, UpdateCore, VariableOutputCore,
     },
     typenum::{Unsigned, U64},
-    HashMarker, Output,
+    HashMarker, InvalidOutputSize, Output,
 };
 
 /// Core Whirlpool hasher state.
@@ -116,6 +116,18 @@ impl FixedOutputCore for WhirlpoolCore {
 }
 
 impl WhirlpoolCore {
+    /// Read-only view of the eight 64-bit lanes of the compression state,
+    /// e.g. for differential testing of alternative compression backends.
+    pub fn state(&self) -> &[u64; 8] {
+        &self.state
+    }
+
+    /// The 256-bit message length counter as four u64 words, most
+    /// significant first.
+    pub fn bit_len(&self) -> [u64; 4] {
+        self.bit_len
+    }
+
     fn update_len(&mut self, len: u64) {
         let mut carry = 0;
         adc(&mut self.bit_len[3], len, &mut carry);
@@ -156,9 +168,408 @@ impl fmt::Debug for WhirlpoolCore {
     }
 }
 
+/// Core Whirlpool hasher state for truncated variable output.
+#[derive(Clone)]
+pub struct WhirlpoolVarCore {
+    core: WhirlpoolCore,
+}
+
+impl HashMarker for WhirlpoolVarCore {}
+
+impl BlockSizeUser for WhirlpoolVarCore {
+    type BlockSize = U64;
+}
+
+impl BufferKindUser for WhirlpoolVarCore {
+    type BufferKind = Eager;
+}
+
+impl OutputSizeUser for WhirlpoolVarCore {
+    type OutputSize = U64;
+}
+
+impl UpdateCore for WhirlpoolVarCore {
+    #[inline]
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        self.core.update_blocks(blocks);
+    }
+}
+
+impl VariableOutputCore for WhirlpoolVarCore {
+    const TRUNC_SIDE: TruncSide = TruncSide::Left;
+
+    #[inline]
+    fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+        if output_size == 0 || output_size > Self::OutputSize::USIZE {
+            return Err(InvalidOutputSize);
+        }
+        Ok(Self {
+            core: Default::default(),
+        })
+    }
+
+    #[inline]
+    fn finalize_variable_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        self.core.finalize_fixed_core(buffer, out);
+    }
+}
+
+impl Reset for WhirlpoolVarCore {
+    #[inline]
+    fn reset(&mut self) {
+        self.core.reset();
+    }
+}
+
+impl AlgorithmName for WhirlpoolVarCore {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WhirlpoolVar")
+    }
+}
+
+impl fmt::Debug for WhirlpoolVarCore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WhirlpoolVarCore { ... }")
+    }
+}
+
 /// Whirlpool hasher state.
 pub type Whirlpool = CoreWrapper<WhirlpoolCore>;
 
+/// Whirlpool hasher state with a runtime-selected output size of
+/// 1 to 64 bytes, truncating the full digest from the left.
+pub type WhirlpoolVar = RtVariableCoreWrapper<WhirlpoolVarCore>;
+
+#[cfg(feature = "oid")]
+impl digest::const_oid::AssociatedOid for WhirlpoolCore {
+    /// The Whirlpool OID from ISO/IEC 10118-3.
+    const OID: digest::const_oid::ObjectIdentifier =
+        digest::const_oid::ObjectIdentifier::new_unwrap("1.0.10118.3.0.55");
+}
+
+/// DER encoding of the Whirlpool OID `1.0.10118.3.0.55` (ISO/IEC 10118-3),
+/// including the OBJECT IDENTIFIER tag and length.
+pub const OID_DER: &[u8] = &[0x06, 0x06, 0x28, 0xcf, 0x06, 0x03, 0x00, 0x37];
+
+// DigestInfo ::= SEQUENCE {
+//     AlgorithmIdentifier ::= SEQUENCE { OID, NULL },
+//     digest OCTET STRING }
+// All lengths are fixed since the digest is always 64 bytes.
+const DIGEST_INFO_PREFIX: [u8; 16] = [
+    0x30, 0x4e, // SEQUENCE, 78 bytes
+    0x30, 0x0a, // SEQUENCE, 10 bytes
+    0x06, 0x06, 0x28, 0xcf, 0x06, 0x03, 0x00, 0x37, // OID 1.0.10118.3.0.55
+    0x05, 0x00, // NULL
+    0x04, 0x40, // OCTET STRING, 64 bytes
+];
+
+/// Builds the DER-encoded `DigestInfo` structure around `digest`, as used
+/// in PKCS#1 v1.5 signatures.
+pub fn digest_info(digest: &Output<Whirlpool>) -> [u8; 80] {
+    let mut out = [0u8; 80];
+    out[..16].copy_from_slice(&DIGEST_INFO_PREFIX);
+    out[16..].copy_from_slice(digest);
+    out
+}
+
+/// Returns the initial Whirlpool compression state.
+pub fn initial_state() -> [u64; 8] {
+    [0u64; 8]
+}
+
+/// Compresses a single 64-byte message block into `state`.
+///
+/// This is the raw compression function as a fixed-input-length
+/// primitive, e.g. for Merkle tree nodes built from two 32-byte child
+/// digests per block. It performs no Merkle–Damgård length bookkeeping
+/// or padding, so it is **not** a drop-in replacement for the [`Digest`]
+/// API: to reproduce a full digest the caller must append the `0x80`
+/// padding byte and the 256-bit big-endian message length themselves.
+/// The final state maps to the digest bytes in little-endian word order.
+pub fn compress_block(state: &mut [u64; 8], block: &[u8; 64]) {
+    compress(state, core::slice::from_ref(block));
+}
+
+/// Compresses a sequence of message blocks into `state` without requiring
+/// the blocks to be contiguous in memory.
+///
+/// This only advances the compression state; length bookkeeping and
+/// padding are the caller's responsibility.
+pub fn compress_iter<'a>(state: &mut [u64; 8], blocks: impl Iterator<Item = &'a [u8; 64]>) {
+    for block in blocks {
+        compress(state, core::slice::from_ref(block));
+    }
+}
+
+/// Feeds each slice in `bufs` into `hasher` as if the slices were
+/// concatenated, letting the eager block buffer stitch partial blocks
+/// together across slice boundaries.
+pub fn update_vectored(hasher: &mut Whirlpool, bufs: &[&[u8]]) {
+    for buf in bufs {
+        hasher.update(buf);
+    }
+}
+
+/// Returns a hasher with a length-prefixed domain string absorbed ahead of
+/// the user data, for domain separation between protocols.
+///
+/// The absorbed prefix is `u64 be length || domain || zero padding` up to
+/// the next 64-byte block boundary, so a personalized hasher and a plain
+/// one can never collide on block boundaries, and distinct domains never
+/// produce related states. Note that this is a crate-specific construction,
+/// not part of the Whirlpool standard.
+pub fn new_with_personalization(domain: &[u8]) -> Whirlpool {
+    let mut hasher = Whirlpool::new();
+    hasher.update((domain.len() as u64).to_be_bytes());
+    hasher.update(domain);
+    let rem = (8 + domain.len()) % BLOCK_SIZE;
+    if rem != 0 {
+        hasher.update(&[0u8; 64][..BLOCK_SIZE - rem]);
+    }
+    hasher
+}
+
+/// Hashes `data` and compares the digest against `expected` in constant
+/// time, i.e. without an early exit on the first mismatching byte.
+pub fn verify(expected: &[u8; 64], data: impl AsRef<[u8]>) -> bool {
+    ct_eq(expected, &Whirlpool::digest(data))
+}
+
+/// The byte-oriented ISO/IEC 10118-3 test vectors run by [`selftest`],
+/// except the million-`'a'` message which is streamed separately.
+const SELFTEST_VECTORS: &[(&str, &[u8], [u8; 64])] = &[
+    (
+        "empty",
+        b"",
+        [
+            0x19, 0xfa, 0x61, 0xd7, 0x55, 0x22, 0xa4, 0x66,
+            0x9b, 0x44, 0xe3, 0x9c, 0x1d, 0x2e, 0x17, 0x26,
+            0xc5, 0x30, 0x23, 0x21, 0x30, 0xd4, 0x07, 0xf8,
+            0x9a, 0xfe, 0xe0, 0x96, 0x49, 0x97, 0xf7, 0xa7,
+            0x3e, 0x83, 0xbe, 0x69, 0x8b, 0x28, 0x8f, 0xeb,
+            0xcf, 0x88, 0xe3, 0xe0, 0x3c, 0x4f, 0x07, 0x57,
+            0xea, 0x89, 0x64, 0xe5, 0x9b, 0x63, 0xd9, 0x37,
+            0x08, 0xb1, 0x38, 0xcc, 0x42, 0xa6, 0x6e, 0xb3,
+        ],
+    ),
+    (
+        "a",
+        b"a",
+        [
+            0x8a, 0xca, 0x26, 0x02, 0x79, 0x2a, 0xec, 0x6f,
+            0x11, 0xa6, 0x72, 0x06, 0x53, 0x1f, 0xb7, 0xd7,
+            0xf0, 0xdf, 0xf5, 0x94, 0x13, 0x14, 0x5e, 0x69,
+            0x73, 0xc4, 0x50, 0x01, 0xd0, 0x08, 0x7b, 0x42,
+            0xd1, 0x1b, 0xc6, 0x45, 0x41, 0x3a, 0xef, 0xf6,
+            0x3a, 0x42, 0x39, 0x1a, 0x39, 0x14, 0x5a, 0x59,
+            0x1a, 0x92, 0x20, 0x0d, 0x56, 0x01, 0x95, 0xe5,
+            0x3b, 0x47, 0x85, 0x84, 0xfd, 0xae, 0x23, 0x1a,
+        ],
+    ),
+    (
+        "abc",
+        b"abc",
+        [
+            0x4e, 0x24, 0x48, 0xa4, 0xc6, 0xf4, 0x86, 0xbb,
+            0x16, 0xb6, 0x56, 0x2c, 0x73, 0xb4, 0x02, 0x0b,
+            0xf3, 0x04, 0x3e, 0x3a, 0x73, 0x1b, 0xce, 0x72,
+            0x1a, 0xe1, 0xb3, 0x03, 0xd9, 0x7e, 0x6d, 0x4c,
+            0x71, 0x81, 0xee, 0xbd, 0xb6, 0xc5, 0x7e, 0x27,
+            0x7d, 0x0e, 0x34, 0x95, 0x71, 0x14, 0xcb, 0xd6,
+            0xc7, 0x97, 0xfc, 0x9d, 0x95, 0xd8, 0xb5, 0x82,
+            0xd2, 0x25, 0x29, 0x20, 0x76, 0xd4, 0xee, 0xf5,
+        ],
+    ),
+    (
+        "message digest",
+        b"message digest",
+        [
+            0x37, 0x8c, 0x84, 0xa4, 0x12, 0x6e, 0x2d, 0xc6,
+            0xe5, 0x6d, 0xcc, 0x74, 0x58, 0x37, 0x7a, 0xac,
+            0x83, 0x8d, 0x00, 0x03, 0x22, 0x30, 0xf5, 0x3c,
+            0xe1, 0xf5, 0x70, 0x0c, 0x0f, 0xfb, 0x4d, 0x3b,
+            0x84, 0x21, 0x55, 0x76, 0x59, 0xef, 0x55, 0xc1,
+            0x06, 0xb4, 0xb5, 0x2a, 0xc5, 0xa4, 0xaa, 0xa6,
+            0x92, 0xed, 0x92, 0x00, 0x52, 0x83, 0x8f, 0x33,
+            0x62, 0xe8, 0x6d, 0xbd, 0x37, 0xa8, 0x90, 0x3e,
+        ],
+    ),
+    (
+        "a..z",
+        b"abcdefghijklmnopqrstuvwxyz",
+        [
+            0xf1, 0xd7, 0x54, 0x66, 0x26, 0x36, 0xff, 0xe9,
+            0x2c, 0x82, 0xeb, 0xb9, 0x21, 0x2a, 0x48, 0x4a,
+            0x8d, 0x38, 0x63, 0x1e, 0xad, 0x42, 0x38, 0xf5,
+            0x44, 0x2e, 0xe1, 0x3b, 0x80, 0x54, 0xe4, 0x1b,
+            0x08, 0xbf, 0x2a, 0x92, 0x51, 0xc3, 0x0b, 0x6a,
+            0x0b, 0x8a, 0xae, 0x86, 0x17, 0x7a, 0xb4, 0xa6,
+            0xf6, 0x8f, 0x67, 0x3e, 0x72, 0x07, 0x86, 0x5d,
+            0x5d, 0x98, 0x19, 0xa3, 0xdb, 0xa4, 0xeb, 0x3b,
+        ],
+    ),
+    (
+        "A..Za..z0..9",
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+        [
+            0xdc, 0x37, 0xe0, 0x08, 0xcf, 0x9e, 0xe6, 0x9b,
+            0xf1, 0x1f, 0x00, 0xed, 0x9a, 0xba, 0x26, 0x90,
+            0x1d, 0xd7, 0xc2, 0x8c, 0xde, 0xc0, 0x66, 0xcc,
+            0x6a, 0xf4, 0x2e, 0x40, 0xf8, 0x2f, 0x3a, 0x1e,
+            0x08, 0xeb, 0xa2, 0x66, 0x29, 0x12, 0x9d, 0x8f,
+            0xb7, 0xcb, 0x57, 0x21, 0x1b, 0x92, 0x81, 0xa6,
+            0x55, 0x17, 0xcc, 0x87, 0x9d, 0x7b, 0x96, 0x21,
+            0x42, 0xc6, 0x5f, 0x5a, 0x7a, 0xf0, 0x14, 0x67,
+        ],
+    ),
+    (
+        "8 x 1234567890",
+        b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+        [
+            0x46, 0x6e, 0xf1, 0x8b, 0xab, 0xb0, 0x15, 0x4d,
+            0x25, 0xb9, 0xd3, 0x8a, 0x64, 0x14, 0xf5, 0xc0,
+            0x87, 0x84, 0x37, 0x2b, 0xcc, 0xb2, 0x04, 0xd6,
+            0x54, 0x9c, 0x4a, 0xfa, 0xdb, 0x60, 0x14, 0x29,
+            0x4d, 0x5b, 0xd8, 0xdf, 0x2a, 0x6c, 0x44, 0xe5,
+            0x38, 0xcd, 0x04, 0x7b, 0x26, 0x81, 0xa5, 0x1a,
+            0x2c, 0x60, 0x48, 0x1e, 0x88, 0xc5, 0xa2, 0x0b,
+            0x2c, 0x2a, 0x80, 0xcf, 0x3a, 0x9a, 0x08, 0x3b,
+        ],
+    ),
+];
+
+/// Expected digest of one million `'a'` bytes.
+const SELFTEST_MILLION_A: [u8; 64] = [
+    0x0c, 0x99, 0x00, 0x5b, 0xeb, 0x57, 0xef, 0xf5,
+    0x0a, 0x7c, 0xf0, 0x05, 0x56, 0x0d, 0xdf, 0x5d,
+    0x29, 0x05, 0x7f, 0xd8, 0x6b, 0x20, 0xbf, 0xd6,
+    0x2d, 0xec, 0xa0, 0xf1, 0xcc, 0xea, 0x4a, 0xf5,
+    0x1f, 0xc1, 0x54, 0x90, 0xed, 0xdc, 0x47, 0xaf,
+    0x32, 0xbb, 0x2b, 0x66, 0xc3, 0x4f, 0xf9, 0xad,
+    0x8c, 0x60, 0x08, 0xad, 0x67, 0x7f, 0x77, 0x12,
+    0x69, 0x53, 0xb2, 0x26, 0xe4, 0xed, 0x8b, 0x01,
+];
+
+/// Error returned by [`selftest`], naming the test vector whose digest
+/// did not match.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SelfTestError {
+    /// Short name of the ISO/IEC 10118-3 vector that failed.
+    pub vector: &'static str,
+}
+
+impl fmt::Display for SelfTestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "whirlpool self-test failed on vector {:?}", self.vector)
+    }
+}
+
+/// Runs the byte-oriented ISO/IEC 10118-3 (NESSIE) test vector set against
+/// the active compression backend and reports the first failing vector.
+///
+/// This is meant as a start-up or integration check that an alternative
+/// backend (e.g. the `asm` feature) agrees with the reference digests. The
+/// million-`'a'` vector is streamed in uneven chunks so the block buffer
+/// and the length counter accumulation are exercised as well.
+pub fn selftest() -> Result<(), SelfTestError> {
+    for &(vector, msg, ref expected) in SELFTEST_VECTORS {
+        if Whirlpool::digest(msg)[..] != expected[..] {
+            return Err(SelfTestError { vector });
+        }
+    }
+    let mut hasher = Whirlpool::new();
+    let chunk = [b'a'; 1000];
+    let mut fed = 0;
+    // uneven chunk sizes keep the buffer position moving
+    for size in [1000, 999, 64, 1].iter().cycle() {
+        if fed + size > 1_000_000 {
+            continue;
+        }
+        hasher.update(&chunk[..*size]);
+        fed += size;
+        if fed == 1_000_000 {
+            break;
+        }
+    }
+    if hasher.finalize()[..] != SELFTEST_MILLION_A[..] {
+        return Err(SelfTestError {
+            vector: "million a",
+        });
+    }
+    Ok(())
+}
+
+/// HMAC-Whirlpool: the standard two-pass HMAC construction over the
+/// 64-byte Whirlpool block size.
+///
+/// Keys longer than the block size are hashed first; shorter keys are
+/// zero-padded, as specified by RFC 2104.
+#[derive(Clone)]
+pub struct WhirlpoolMac {
+    inner: Whirlpool,
+    outer: Whirlpool,
+}
+
+impl WhirlpoolMac {
+    /// Creates a MAC instance keyed with `key`.
+    pub fn new(key: &[u8]) -> Self {
+        let mut padded = [0u8; BLOCK_SIZE];
+        if key.len() > BLOCK_SIZE {
+            padded.copy_from_slice(&Whirlpool::digest(key));
+        } else {
+            padded[..key.len()].copy_from_slice(key);
+        }
+
+        let mut block = [0u8; BLOCK_SIZE];
+        for (b, k) in block.iter_mut().zip(padded.iter()) {
+            *b = k ^ 0x36;
+        }
+        let mut inner = Whirlpool::new();
+        inner.update(block);
+
+        for (b, k) in block.iter_mut().zip(padded.iter()) {
+            *b = k ^ 0x5c;
+        }
+        let mut outer = Whirlpool::new();
+        outer.update(block);
+
+        Self { inner, outer }
+    }
+
+    /// Feeds message data into the MAC.
+    pub fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.inner.update(data);
+    }
+
+    /// Consumes the MAC and returns the authentication tag.
+    pub fn finalize(self) -> Output<WhirlpoolCore> {
+        let mut outer = self.outer;
+        outer.update(self.inner.finalize());
+        outer.finalize()
+    }
+
+    /// Consumes the MAC and compares the tag against `expected` in
+    /// constant time.
+    pub fn verify(self, expected: &[u8; 64]) -> bool {
+        ct_eq(expected, &self.finalize())
+    }
+}
+
+impl fmt::Debug for WhirlpoolMac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WhirlpoolMac { ... }")
+    }
+}
+
+#[inline]
+fn ct_eq(expected: &[u8; 64], actual: &[u8]) -> bool {
+    let mut diff = 0u8;
+    for (a, b) in expected.iter().zip(actual.iter()) {
+        diff |= a ^ b;
+    }
+    diff == 0
+}
+
 #[inline(always)]
 fn adc(a: &mut u64, b: u64, carry: &mut u64) {
     let ret = (*a as u128) + (b as u128) + (*carry as u128);
@@ -263,4 +674,20 @@ mod tests_rug_433 {
 
         p0.reset();
     }
-}
\ No newline at end of file
+}#[cfg(test)]
+mod update_len_tests {
+    use super::WhirlpoolCore;
+
+    #[test]
+    fn test_update_len_carry() {
+        let mut core = WhirlpoolCore::default();
+        core.update_len(u64::MAX);
+        assert_eq!(core.bit_len(), [0, 0, 0, u64::MAX]);
+        // adding one more bit must carry into bit_len[2]
+        core.update_len(1);
+        assert_eq!(core.bit_len(), [0, 0, 1, 0]);
+        core.update_len(u64::MAX);
+        core.update_len(2);
+        assert_eq!(core.bit_len(), [0, 0, 2, 1]);
+    }
+}
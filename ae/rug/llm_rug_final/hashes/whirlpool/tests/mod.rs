@@ -17,3 +17,278 @@ fn whirlpool_rand() {
         ")[..]
     );
 }
+
+#[test]
+fn whirlpool_verify_bit_flip() {
+    let data = b"integrity tagged message";
+    let mut expected = [0u8; 64];
+    expected.copy_from_slice(&Whirlpool::digest(data));
+
+    assert!(whirlpool::verify(&expected, data));
+    assert!(!whirlpool::verify(&expected, b"integrity tagged messagf"));
+
+    // flipping any single bit of the expected tag must fail verification
+    for byte in 0..64 {
+        for bit in 0..8 {
+            let mut bad = expected;
+            bad[byte] ^= 1 << bit;
+            assert!(!whirlpool::verify(&bad, data));
+        }
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn hmac_whirlpool_vectors() {
+    use whirlpool::WhirlpoolMac;
+
+    // Vectors computed with OpenSSL's HMAC over the legacy whirlpool digest.
+    // Key shorter than the 64-byte block size (zero-padded).
+    let mut mac = WhirlpoolMac::new(&[0x0b; 20]);
+    mac.update(b"Hi There");
+    assert_eq!(
+        mac.finalize()[..],
+        hex!("
+            8a2c9b1ccf4b28660de78af9db15b7c94d129ec960ca9a950a665ea5e88362e2
+            4f4474354e18512d956d9bb7e6bbbb50b9ba0d3093b0a17c6ec2aa91e57169ce
+        ")[..]
+    );
+
+    let mut mac = WhirlpoolMac::new(b"Jefe");
+    mac.update(b"what do ya want for nothing?");
+    assert_eq!(
+        mac.finalize()[..],
+        hex!("
+            3d595ccd1d4f4cfd045af53ba7d5c8283fee6ded6eaf1269071b6b4ea6480005
+            6b5077c6a942cfa1221bd4e5aed791276e5dd46a407d2b8007163d3e7cd1de66
+        ")[..]
+    );
+
+    // Key of exactly one block.
+    let key: Vec<u8> = (0u8..64).collect();
+    let mut mac = WhirlpoolMac::new(&key);
+    mac.update(b"block-sized key");
+    assert_eq!(
+        mac.finalize()[..],
+        hex!("
+            52b65fd0d529d7fabc8580d1aef72b05260631c63b5741816729b286ae7a1d2f
+            02dea6872c45ba88210c3e90b4c76e967944894ed6c6f4aa85acc20804df66ac
+        ")[..]
+    );
+
+    // Key longer than the block size (hashed first).
+    let mut mac = WhirlpoolMac::new(&[0xaa; 80]);
+    mac.update(b"Test Using Larger Than Block-Size Key - Hash Key First");
+    let tag = mac.clone().finalize();
+    assert_eq!(
+        tag[..],
+        hex!("
+            dd90bd637cfcd27ca914c290f33402cf68576d6e70601af0295f6b9dafa9d988
+            d8b8fb4fde8605ac544638158dba2baa90a2bf882546cd0b876d59ab3f18962e
+        ")[..]
+    );
+
+    let mut expected = [0u8; 64];
+    expected.copy_from_slice(&tag);
+    assert!(mac.clone().verify(&expected));
+    expected[0] ^= 1;
+    assert!(!mac.verify(&expected));
+}
+
+#[test]
+fn update_vectored_matches_one_shot() {
+    let msg: Vec<u8> = (0..10 * 1024).map(|i| (i * 31 % 251) as u8).collect();
+
+    // split at awkward boundaries relative to the 64-byte block size
+    let splits = [1usize, 63, 64, 65, 4095];
+    let mut bufs: Vec<&[u8]> = Vec::new();
+    let mut pos = 0;
+    for &n in &splits {
+        bufs.push(&msg[pos..pos + n]);
+        pos += n;
+    }
+    bufs.push(&msg[pos..]);
+
+    let mut h = Whirlpool::new();
+    whirlpool::update_vectored(&mut h, &bufs);
+    assert_eq!(h.finalize()[..], Whirlpool::digest(&msg)[..]);
+}
+
+#[test]
+fn compress_iter_matches_digest() {
+    // one full data block followed by a hand-built padding block
+    let msg = [0x61u8; 64];
+    let mut pad = [0u8; 64];
+    pad[0] = 0x80;
+    pad[56..].copy_from_slice(&(64u64 * 8).to_be_bytes());
+
+    let mut state = [0u64; 8];
+    whirlpool::compress_iter(&mut state, [&msg, &pad].iter().copied());
+
+    let mut out = [0u8; 64];
+    for (chunk, v) in out.chunks_exact_mut(8).zip(state.iter()) {
+        chunk.copy_from_slice(&v.to_le_bytes());
+    }
+    assert_eq!(out[..], Whirlpool::digest(msg)[..]);
+}
+
+#[test]
+fn digest_info_prefix_matches_reference_encoding() {
+    // Rebuild the DER header from first principles and compare.
+    // OID 1.0.10118.3.0.55 encodes as 28 CF 06 03 00 37.
+    let oid_body = [0x28, 0xcf, 0x06, 0x03, 0x00, 0x37];
+    let mut oid_der = vec![0x06, oid_body.len() as u8];
+    oid_der.extend_from_slice(&oid_body);
+    assert_eq!(whirlpool::OID_DER, &oid_der[..]);
+
+    // AlgorithmIdentifier = SEQUENCE { OID, NULL }
+    let mut alg_id = vec![0x30, (oid_der.len() + 2) as u8];
+    alg_id.extend_from_slice(&oid_der);
+    alg_id.extend_from_slice(&[0x05, 0x00]);
+
+    // DigestInfo = SEQUENCE { AlgorithmIdentifier, OCTET STRING (64) }
+    let digest = Whirlpool::digest(b"abc");
+    let mut reference = vec![0x30, (alg_id.len() + 2 + 64) as u8];
+    reference.extend_from_slice(&alg_id);
+    reference.extend_from_slice(&[0x04, 0x40]);
+    reference.extend_from_slice(&digest);
+
+    assert_eq!(whirlpool::digest_info(&digest)[..], reference[..]);
+}
+
+#[test]
+fn digest_info_empty_message() {
+    let digest = Whirlpool::digest(b"");
+    let info = whirlpool::digest_info(&digest);
+    assert_eq!(info.len(), 80);
+    assert_eq!(info[0], 0x30);
+    assert_eq!(info[1], 0x4e);
+    assert_eq!(&info[4..12], whirlpool::OID_DER);
+    assert_eq!(&info[12..16], &[0x05, 0x00, 0x04, 0x40]);
+    assert_eq!(info[16..], digest[..]);
+}
+
+#[test]
+fn whirlpool_var_truncates_full_digest() {
+    use digest::{Update, VariableOutput};
+    use whirlpool::WhirlpoolVar;
+
+    let msg = b"The quick brown fox jumps over the lazy dog";
+    let full = Whirlpool::digest(msg);
+
+    let mut h = WhirlpoolVar::new(64).unwrap();
+    h.update(msg);
+    let mut out = [0u8; 64];
+    h.finalize_variable(&mut out).unwrap();
+    assert_eq!(out[..], full[..]);
+
+    let mut h = WhirlpoolVar::new(32).unwrap();
+    h.update(msg);
+    let mut out = [0u8; 32];
+    h.finalize_variable(&mut out).unwrap();
+    assert_eq!(out[..], full[..32]);
+
+    assert!(WhirlpoolVar::new(0).is_err());
+    assert!(WhirlpoolVar::new(65).is_err());
+}
+
+#[test]
+fn personalization_separates_domains() {
+    let msg = b"same message";
+
+    let mut a = whirlpool::new_with_personalization(b"proto-a");
+    a.update(msg);
+    let mut b = whirlpool::new_with_personalization(b"proto-b");
+    b.update(msg);
+    assert_ne!(a.finalize()[..], b.finalize()[..]);
+
+    // deterministic for a fixed domain
+    let mut first = whirlpool::new_with_personalization(b"proto-a");
+    first.update(msg);
+    let mut second = whirlpool::new_with_personalization(b"proto-a");
+    second.update(msg);
+    assert_eq!(first.finalize()[..], second.finalize()[..]);
+
+    // even the empty domain absorbs a block, so it differs from plain hashing
+    let mut empty = whirlpool::new_with_personalization(b"");
+    empty.update(msg);
+    assert_ne!(empty.finalize()[..], Whirlpool::digest(msg)[..]);
+
+    // a domain longer than one block is padded to the next boundary
+    let mut long = whirlpool::new_with_personalization(&[0x5a; 100]);
+    long.update(msg);
+    let mut manual = Whirlpool::new();
+    manual.update(100u64.to_be_bytes());
+    manual.update([0x5a; 100]);
+    manual.update([0u8; 20]);
+    manual.update(msg);
+    assert_eq!(long.finalize()[..], manual.finalize()[..]);
+}
+
+#[test]
+fn compress_block_reproduces_short_message_digest() {
+    use whirlpool::{compress_block, initial_state, Digest, Whirlpool};
+
+    // manually pad "abc": message || 0x80 || zeros || 256-bit big-endian bit length
+    let msg = b"abc";
+    let mut block = [0u8; 64];
+    block[..msg.len()].copy_from_slice(msg);
+    block[msg.len()] = 0x80;
+    let bit_len = (8 * msg.len()) as u64;
+    block[56..].copy_from_slice(&bit_len.to_be_bytes());
+
+    let mut state = initial_state();
+    compress_block(&mut state, &block);
+
+    let mut manual = [0u8; 64];
+    for (chunk, v) in manual.chunks_exact_mut(8).zip(state.iter()) {
+        chunk.copy_from_slice(&v.to_le_bytes());
+    }
+
+    assert_eq!(manual[..], Whirlpool::digest(msg)[..]);
+
+    // a message long enough to need an extra padding block
+    let msg = [0xabu8; 60];
+    let mut first = [0u8; 64];
+    first[..60].copy_from_slice(&msg);
+    first[60] = 0x80;
+    let mut second = [0u8; 64];
+    let bit_len = (8 * msg.len()) as u64;
+    second[56..].copy_from_slice(&bit_len.to_be_bytes());
+
+    let mut state = initial_state();
+    compress_block(&mut state, &first);
+    compress_block(&mut state, &second);
+
+    let mut manual = [0u8; 64];
+    for (chunk, v) in manual.chunks_exact_mut(8).zip(state.iter()) {
+        chunk.copy_from_slice(&v.to_le_bytes());
+    }
+
+    assert_eq!(manual[..], Whirlpool::digest(msg)[..]);
+}
+
+#[test]
+fn selftest_passes() {
+    whirlpool::selftest().unwrap();
+}
+
+#[test]
+fn core_state_accessors() {
+    use digest::core_api::{Block, UpdateCore};
+    use whirlpool::WhirlpoolCore;
+
+    let core = WhirlpoolCore::default();
+    assert_eq!(*core.state(), whirlpool::initial_state());
+    assert_eq!(core.bit_len(), [0u64; 4]);
+
+    // after one block the lanes must match the raw compression function
+    // and the counter must hold 512 bits
+    let block = Block::<WhirlpoolCore>::from([0x5au8; 64]);
+    let mut core = WhirlpoolCore::default();
+    core.update_blocks(core::slice::from_ref(&block));
+    let mut expected = whirlpool::initial_state();
+    whirlpool::compress_block(&mut expected, &[0x5au8; 64]);
+    assert_eq!(*core.state(), expected);
+    assert_eq!(core.bit_len(), [0, 0, 0, 512]);
+}
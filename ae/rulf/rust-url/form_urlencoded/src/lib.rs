@@ -28,31 +28,88 @@ pub struct Parse<'a> {
 impl<'a> Iterator for Parse<'a> {
     type Item = (Cow<'a, str>, Cow<'a, str>);
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            if self.input.is_empty() {
-                return None;
-            }
-            let mut split2 = self.input.splitn(2, |&b| b == b'&');
-            let sequence = split2.next().unwrap();
-            self.input = split2.next().unwrap_or(&[][..]);
-            if sequence.is_empty() {
-                continue;
-            }
-            let mut split2 = sequence.splitn(2, |&b| b == b'=');
-            let name = split2.next().unwrap();
-            let value = split2.next().unwrap_or(&[][..]);
-            return Some((decode(name), decode(value)));
+        let (name, value) = next_pair(&mut self.input)?;
+        Some((decode(name), decode(value)))
+    }
+}
+/// Split off the next `name=value` sequence, skipping empty ones,
+/// without any decoding.
+fn next_pair<'a>(input: &mut &'a [u8]) -> Option<(&'a [u8], &'a [u8])> {
+    loop {
+        if input.is_empty() {
+            return None;
         }
+        let mut split2 = input.splitn(2, |&b| b == b'&');
+        let sequence = split2.next().unwrap();
+        *input = split2.next().unwrap_or(&[][..]);
+        if sequence.is_empty() {
+            continue;
+        }
+        let mut split2 = sequence.splitn(2, |&b| b == b'=');
+        let name = split2.next().unwrap();
+        let value = split2.next().unwrap_or(&[][..]);
+        return Some((name, value));
     }
 }
 fn decode(input: &[u8]) -> Cow<'_, str> {
+    decode_utf8_lossy(decode_bytes(input))
+}
+/// Replace `+` and percent-decode, without any UTF-8 interpretation.
+fn decode_bytes(input: &[u8]) -> Cow<'_, [u8]> {
     let replaced = replace_plus(input);
-    decode_utf8_lossy(
-        match percent_decode(&replaced).into() {
-            Cow::Owned(vec) => Cow::Owned(vec),
-            Cow::Borrowed(_) => replaced,
-        },
-    )
+    match percent_decode(&replaced).into() {
+        Cow::Owned(vec) => Cow::Owned(vec),
+        Cow::Borrowed(_) => replaced,
+    }
+}
+fn decode_strict(input: &[u8]) -> Result<Cow<'_, str>, str::Utf8Error> {
+    match decode_bytes(input) {
+        Cow::Borrowed(bytes) => Ok(Cow::Borrowed(str::from_utf8(bytes)?)),
+        Cow::Owned(bytes) => Ok(Cow::Owned(
+            String::from_utf8(bytes).map_err(|e| e.utf8_error())?,
+        )),
+    }
+}
+/// Like `parse()`, but yields the raw percent-decoded bytes of each name
+/// and value instead of lossily converting them to UTF-8.
+///
+/// This preserves percent-encoded bytes in legacy encodings
+/// (e.g. Shift-JIS form submissions) that `parse()` would replace with
+/// U+FFFD.
+#[inline]
+pub fn parse_bytes(input: &[u8]) -> ParseBytes<'_> {
+    ParseBytes { input }
+}
+/// The return type of `parse_bytes()`.
+#[derive(Copy, Clone)]
+pub struct ParseBytes<'a> {
+    input: &'a [u8],
+}
+impl<'a> Iterator for ParseBytes<'a> {
+    type Item = (Cow<'a, [u8]>, Cow<'a, [u8]>);
+    fn next(&mut self) -> Option<Self::Item> {
+        let (name, value) = next_pair(&mut self.input)?;
+        Some((decode_bytes(name), decode_bytes(value)))
+    }
+}
+/// Like `parse()`, but yields an `Err` for a name/value pair whose
+/// percent-decoded bytes are not valid UTF-8, instead of replacing them
+/// with U+FFFD.
+#[inline]
+pub fn parse_strict(input: &[u8]) -> ParseStrict<'_> {
+    ParseStrict { input }
+}
+/// The return type of `parse_strict()`.
+#[derive(Copy, Clone)]
+pub struct ParseStrict<'a> {
+    input: &'a [u8],
+}
+impl<'a> Iterator for ParseStrict<'a> {
+    type Item = Result<(Cow<'a, str>, Cow<'a, str>), str::Utf8Error>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (name, value) = next_pair(&mut self.input)?;
+        Some(decode_strict(name).and_then(|name| Ok((name, decode_strict(value)?))))
+    }
 }
 /// Replace b'+' with b' '
 fn replace_plus(input: &[u8]) -> Cow<'_, [u8]> {
@@ -0,0 +1,276 @@
+use std::borrow::Borrow;
+use std::fmt::Write;
+
+use percent_encoding::utf8_percent_encode;
+
+use crate::host::HostInternal;
+use crate::parser::{
+    self, to_u32, ParseError, SchemeType, FRAGMENT, PATH, PATH_SEGMENT, USERINFO,
+};
+use crate::{Host, Url};
+/// A builder to construct a [`Url`] piece by piece.
+///
+/// Each setter records the given component; [`build`](UrlBuilder::build) writes
+/// the serialization once with the same percent-encoding the parser would
+/// apply, without going through a full re-parse. Special-scheme rules are
+/// enforced at build time: `http`, `https`, `ws`, `wss`, `ftp` and `file`
+/// URLs must have a host (given through [`Host::parse`], so IDNA applies),
+/// and an explicit default port is suppressed.
+///
+/// Note that the path is written as given apart from encoding: `.` and `..`
+/// segments are not resolved like the parser would.
+///
+/// Examples:
+///
+/// ```rust
+/// use url::UrlBuilder;
+/// # use url::ParseError;
+///
+/// # fn run() -> Result<(), ParseError> {
+/// let url = UrlBuilder::new("https")
+///     .host("example.com")
+///     .port(8443)
+///     .path("/a/b")
+///     .query_pairs(&[("x", "1")])
+///     .fragment("f")
+///     .build()?;
+/// assert_eq!(url.as_str(), "https://example.com:8443/a/b?x=1#f");
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct UrlBuilder {
+    scheme: String,
+    username: String,
+    password: Option<String>,
+    host: Option<String>,
+    port: Option<u16>,
+    path: PathRepr,
+    query: Option<String>,
+    fragment: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+enum PathRepr {
+    Raw(String),
+    Segments(Vec<String>),
+}
+
+impl UrlBuilder {
+    /// Start building a URL with the given scheme.
+    pub fn new(scheme: &str) -> UrlBuilder {
+        UrlBuilder {
+            scheme: scheme.to_owned(),
+            username: String::new(),
+            password: None,
+            host: None,
+            port: None,
+            path: PathRepr::Raw(String::new()),
+            query: None,
+            fragment: None,
+        }
+    }
+    /// Set the username, percent-encoded with the userinfo set on build.
+    pub fn username(mut self, username: &str) -> UrlBuilder {
+        self.username = username.to_owned();
+        self
+    }
+    /// Set the password, percent-encoded with the userinfo set on build.
+    pub fn password(mut self, password: &str) -> UrlBuilder {
+        self.password = Some(password.to_owned());
+        self
+    }
+    /// Set the host.
+    ///
+    /// The host is run through [`Host::parse`] (or [`Host::parse_opaque`]
+    /// for non-special schemes) on build, so domains are IDNA-normalized
+    /// and IP addresses are parsed and reserialized.
+    pub fn host(mut self, host: &str) -> UrlBuilder {
+        self.host = Some(host.to_owned());
+        self
+    }
+    /// Set the port number. A default port for the scheme is suppressed
+    /// on build, like the parser does.
+    pub fn port(mut self, port: u16) -> UrlBuilder {
+        self.port = Some(port);
+        self
+    }
+    /// Set the path from a pre-assembled string.
+    ///
+    /// `/`-separated segments are kept as given; characters outside the
+    /// path set (spaces, control characters, …) are percent-encoded on
+    /// build, but existing `%` sequences are left alone.
+    pub fn path(mut self, path: &str) -> UrlBuilder {
+        self.path = PathRepr::Raw(path.to_owned());
+        self
+    }
+    /// Set the path from individual segments, percent-encoded with the
+    /// path-segment set on build, so a `/` inside a segment becomes `%2F`.
+    pub fn path_segments<I>(mut self, segments: I) -> UrlBuilder
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.path = PathRepr::Segments(
+            segments
+                .into_iter()
+                .map(|segment| segment.as_ref().to_owned())
+                .collect(),
+        );
+        self
+    }
+    /// Set the query string from name/value pairs, serialized
+    /// in `application/x-www-form-urlencoded` syntax.
+    pub fn query_pairs<I, K, V>(mut self, pairs: I) -> UrlBuilder
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut serializer = form_urlencoded::Serializer::new(String::new());
+        serializer.extend_pairs(pairs);
+        self.query = Some(serializer.finish());
+        self
+    }
+    /// Set the fragment identifier, percent-encoded with the fragment set
+    /// on build.
+    pub fn fragment(mut self, fragment: &str) -> UrlBuilder {
+        self.fragment = Some(fragment.to_owned());
+        self
+    }
+    /// Write the serialization and assemble the `Url`.
+    ///
+    /// Errors use the parser's vocabulary: an unusable scheme is
+    /// [`ParseError::InvalidScheme`], a missing or unparseable host for a
+    /// special scheme surfaces as the corresponding host parsing error,
+    /// and a hostless path starting with `//` is rejected (it would
+    /// deserialize as an authority).
+    pub fn build(self) -> Result<Url, ParseError> {
+        let valid_scheme = !self.scheme.is_empty()
+            && self.scheme.as_bytes()[0].is_ascii_alphabetic()
+            && self
+                .scheme
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'));
+        if !valid_scheme {
+            return Err(ParseError::InvalidScheme);
+        }
+        let scheme = self.scheme.to_ascii_lowercase();
+        let scheme_type = SchemeType::from(&scheme);
+
+        let mut serialization = String::new();
+        serialization.push_str(&scheme);
+        let scheme_end = to_u32(serialization.len())?;
+        serialization.push(':');
+
+        let has_host = self.host.is_some();
+        let username_end;
+        let host_start;
+        let host_end;
+        let host;
+        let port;
+        if let Some(host_input) = self.host {
+            serialization.push_str("//");
+            if !self.username.is_empty() || self.password.is_some() {
+                serialization.extend(utf8_percent_encode(&self.username, USERINFO));
+                username_end = to_u32(serialization.len())?;
+                if let Some(password) = self.password {
+                    serialization.push(':');
+                    serialization.extend(utf8_percent_encode(&password, USERINFO));
+                }
+                serialization.push('@');
+            } else {
+                username_end = to_u32(serialization.len())?;
+            }
+            host_start = to_u32(serialization.len())?;
+            let parsed_host = if scheme_type.is_special() {
+                Host::parse(&host_input)?
+            } else {
+                Host::parse_opaque(&host_input)?
+            };
+            write!(&mut serialization, "{}", parsed_host).unwrap();
+            host_end = to_u32(serialization.len())?;
+            host = HostInternal::from(parsed_host);
+            port = match self.port {
+                Some(port) if parser::default_port(&scheme) == Some(port) => None,
+                port => port,
+            };
+            if let Some(port) = port {
+                write!(&mut serialization, ":{}", port).unwrap();
+            }
+        } else {
+            if scheme_type.is_special() {
+                return Err(ParseError::EmptyHost);
+            }
+            username_end = scheme_end + 1;
+            host_start = scheme_end + 1;
+            host_end = scheme_end + 1;
+            host = HostInternal::None;
+            port = None;
+        }
+
+        let path_start = to_u32(serialization.len())?;
+        match self.path {
+            PathRepr::Segments(segments) => {
+                if segments.is_empty() && scheme_type.is_special() {
+                    serialization.push('/');
+                }
+                for segment in &segments {
+                    serialization.push('/');
+                    serialization.extend(utf8_percent_encode(segment, PATH_SEGMENT));
+                }
+            }
+            PathRepr::Raw(path) => {
+                if path.is_empty() {
+                    if scheme_type.is_special() {
+                        serialization.push('/');
+                    }
+                } else {
+                    if !has_host && path.starts_with("//") {
+                        return Err(ParseError::EmptyHost);
+                    }
+                    if has_host && !path.starts_with('/') {
+                        serialization.push('/');
+                    }
+                    serialization.extend(utf8_percent_encode(&path, PATH));
+                }
+            }
+        }
+
+        let query_start = match self.query {
+            Some(query) => {
+                let start = to_u32(serialization.len())?;
+                serialization.push('?');
+                serialization.push_str(&query);
+                Some(start)
+            }
+            None => None,
+        };
+        let fragment_start = match self.fragment {
+            Some(fragment) => {
+                let start = to_u32(serialization.len())?;
+                serialization.push('#');
+                serialization.extend(utf8_percent_encode(&fragment, FRAGMENT));
+                Some(start)
+            }
+            None => None,
+        };
+
+        let url = Url {
+            serialization,
+            scheme_end,
+            username_end,
+            host_start,
+            host_end,
+            host,
+            port,
+            path_start,
+            query_start,
+            fragment_start,
+        };
+        debug_assert!(url.check_invariants().is_ok());
+        Ok(url)
+    }
+}
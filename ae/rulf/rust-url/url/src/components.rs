@@ -0,0 +1,158 @@
+use std::borrow::Cow;
+use std::str;
+
+use crate::{Host, Url};
+
+/// A single URL component, as yielded by [`Url::components`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Component<'a> {
+    /// The scheme, without the trailing `:`.
+    Scheme(&'a str),
+    /// The username, if non-empty.
+    Username(&'a str),
+    /// The password, if any.
+    Password(&'a str),
+    /// The host, if any.
+    Host(Host<&'a str>),
+    /// The port number, if explicitly present in the serialization.
+    Port(u16),
+    /// One `/`-separated path segment, percent-encoded like
+    /// [`Url::path_segments`]; for cannot-be-a-base URLs the whole
+    /// path is yielded as a single segment.
+    PathSegment(&'a str),
+    /// One percent-decoded name/value pair from the query string, like
+    /// [`Url::query_pairs`].
+    QueryPair(Cow<'a, str>, Cow<'a, str>),
+    /// The fragment identifier, without the leading `#`.
+    Fragment(&'a str),
+}
+
+/// An iterator over the components of a URL, returned by
+/// [`Url::components`].
+#[derive(Clone)]
+pub struct Components<'a> {
+    url: &'a Url,
+    stage: Stage,
+    opaque_path: Option<&'a str>,
+    path_segments: Option<str::Split<'a, char>>,
+    query_pairs: form_urlencoded::Parse<'a>,
+}
+
+#[derive(Clone, Copy)]
+enum Stage {
+    Scheme,
+    Username,
+    Password,
+    Host,
+    Port,
+    Path,
+    Query,
+    Fragment,
+    Done,
+}
+
+impl<'a> Iterator for Components<'a> {
+    type Item = Component<'a>;
+
+    fn next(&mut self) -> Option<Component<'a>> {
+        loop {
+            match self.stage {
+                Stage::Scheme => {
+                    self.stage = Stage::Username;
+                    return Some(Component::Scheme(self.url.scheme()));
+                }
+                Stage::Username => {
+                    self.stage = Stage::Password;
+                    let username = self.url.username();
+                    if !username.is_empty() {
+                        return Some(Component::Username(username));
+                    }
+                }
+                Stage::Password => {
+                    self.stage = Stage::Host;
+                    if let Some(password) = self.url.password() {
+                        return Some(Component::Password(password));
+                    }
+                }
+                Stage::Host => {
+                    self.stage = Stage::Port;
+                    if let Some(host) = self.url.host() {
+                        return Some(Component::Host(host));
+                    }
+                }
+                Stage::Port => {
+                    self.stage = Stage::Path;
+                    if let Some(port) = self.url.port() {
+                        return Some(Component::Port(port));
+                    }
+                }
+                Stage::Path => {
+                    if let Some(path) = self.opaque_path.take() {
+                        self.stage = Stage::Query;
+                        return Some(Component::PathSegment(path));
+                    }
+                    match self.path_segments.as_mut().and_then(Iterator::next) {
+                        Some(segment) => return Some(Component::PathSegment(segment)),
+                        None => self.stage = Stage::Query,
+                    }
+                }
+                Stage::Query => match self.query_pairs.next() {
+                    Some((name, value)) => return Some(Component::QueryPair(name, value)),
+                    None => self.stage = Stage::Fragment,
+                },
+                Stage::Fragment => {
+                    self.stage = Stage::Done;
+                    if let Some(fragment) = self.url.fragment() {
+                        return Some(Component::Fragment(fragment));
+                    }
+                }
+                Stage::Done => return None,
+            }
+        }
+    }
+}
+
+impl Url {
+    /// Return an iterator over the components of the URL in serialization
+    /// order, e.g. to emit a URL as structured log fields without calling
+    /// each accessor in turn.
+    ///
+    /// Absent components are skipped rather than yielded as empty: there is
+    /// no `Username` item for an empty username, no `Port` item for a
+    /// default port elided from the serialization, and so on. Path segments
+    /// and query pairs stream from the serialization one at a time, like
+    /// [`path_segments`](Url::path_segments) and
+    /// [`query_pairs`](Url::query_pairs), without collecting into vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use url::{Component, Url};
+    /// # use url::ParseError;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let url = Url::parse("https://user@example.com:8080/a/b?x=1#top")?;
+    /// let components: Vec<_> = url.components().collect();
+    /// assert_eq!(components[0], Component::Scheme("https"));
+    /// assert_eq!(components[1], Component::Username("user"));
+    /// assert_eq!(components[4], Component::PathSegment("a"));
+    /// assert_eq!(components.last(), Some(&Component::Fragment("top")));
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn components(&self) -> Components<'_> {
+        let opaque_path = if self.cannot_be_a_base() && !self.path().is_empty() {
+            Some(self.path())
+        } else {
+            None
+        };
+        Components {
+            url: self,
+            stage: Stage::Scheme,
+            opaque_path,
+            path_segments: self.path_segments(),
+            query_pairs: form_urlencoded::parse(self.query().unwrap_or("").as_bytes()),
+        }
+    }
+}
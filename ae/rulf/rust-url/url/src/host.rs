@@ -1,6 +1,7 @@
 use std::cmp;
 use std::fmt::{self, Formatter};
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str;
 use percent_encoding::{percent_decode, utf8_percent_encode, CONTROLS};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -24,7 +25,6 @@ impl From<Host<String>> for HostInternal {
     }
 }
 /// The host name of an URL.
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum Host<S = String> {
     /// A DNS domain name, as '.' dot-separated labels.
@@ -103,6 +103,110 @@ impl Host<String> {
         }
     }
 }
+impl<S> Host<S> {
+    /// Return the IP address this host represents, if it is one.
+    ///
+    /// Domains return `None`, even when they happen to resolve to a single
+    /// address; no name resolution is performed.
+    pub fn to_ip_addr(&self) -> Option<IpAddr> {
+        match *self {
+            Host::Domain(_) => None,
+            Host::Ipv4(address) => Some(IpAddr::V4(address)),
+            Host::Ipv6(address) => Some(IpAddr::V6(address)),
+        }
+    }
+}
+impl<S: AsRef<str>> Host<S> {
+    /// Returns whether this host domain-matches `suffix`, in the sense of
+    /// [RFC 6265 section 5.1.3](https://tools.ietf.org/html/rfc6265#section-5.1.3):
+    /// either the two are identical, or `suffix` is a dot-separated suffix
+    /// of this domain starting on a label boundary. IP addresses never
+    /// suffix-match, only compare equal.
+    ///
+    /// `suffix` is normalized through [`Host::parse`] first, so comparison
+    /// is ASCII case-insensitive and a Unicode suffix matches the
+    /// IDNA-encoded host. An unparseable (e.g. empty) suffix matches
+    /// nothing.
+    pub fn matches_suffix(&self, suffix: &str) -> bool {
+        let parsed = match Host::parse(suffix) {
+            Ok(parsed) => parsed,
+            Err(_) => return false,
+        };
+        match (self, &parsed) {
+            (Host::Ipv4(address), Host::Ipv4(suffix)) => address == suffix,
+            (Host::Ipv6(address), Host::Ipv6(suffix)) => address == suffix,
+            (Host::Domain(domain), Host::Domain(suffix)) => {
+                let domain = domain.as_ref().as_bytes();
+                let suffix = suffix.as_bytes();
+                if domain.len() == suffix.len() {
+                    domain.eq_ignore_ascii_case(suffix)
+                } else {
+                    // label-boundary aware: the byte before the suffix
+                    // must be a dot, so `ample.com` does not match
+                    domain.len() > suffix.len()
+                        && domain[domain.len() - suffix.len() - 1] == b'.'
+                        && domain[domain.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
+                }
+            }
+            _ => false,
+        }
+    }
+}
+/// Parse a host: equivalent to `Host::parse`.
+impl str::FromStr for Host<String> {
+    type Err = ParseError;
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Host::parse(input)
+    }
+}
+/// Serializes this host as its string representation, with IPv6 addresses
+/// between `[` and `]` brackets.
+///
+/// This implementation is only available if the `serde` Cargo feature is enabled.
+#[cfg(feature = "serde")]
+impl<S: AsRef<str>> serde::Serialize for Host<S> {
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+/// Deserializes this host from its string representation, via `Host::parse`.
+///
+/// This implementation is only available if the `serde` Cargo feature is enabled.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Host<String> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::{Error, Visitor};
+        struct HostVisitor;
+        impl<'de> Visitor<'de> for HostVisitor {
+            type Value = Host<String>;
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a string representing a host")
+            }
+            fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Host::parse(s).map_err(Error::custom)
+            }
+        }
+        deserializer.deserialize_str(HostVisitor)
+    }
+}
+impl From<IpAddr> for Host<String> {
+    fn from(address: IpAddr) -> Self {
+        match address {
+            IpAddr::V4(address) => Host::Ipv4(address),
+            IpAddr::V6(address) => Host::Ipv6(address),
+        }
+    }
+}
 impl<S: AsRef<str>> fmt::Display for Host<S> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match *self {
@@ -59,6 +59,16 @@ impl Origin {
     pub fn is_tuple(&self) -> bool {
         matches!(* self, Origin::Tuple(..))
     }
+    /// Return whether this origin and `other` are the same origin.
+    ///
+    /// Tuple origins compare by their (scheme, host, port) components; an
+    /// opaque origin is only the same origin as clones of itself (two
+    /// independently created opaque origins are never the same, even for
+    /// equal URLs). See [`Url::same_origin`](crate::Url::same_origin) for
+    /// comparing URLs without constructing `Origin` values.
+    pub fn is_same_origin(&self, other: &Origin) -> bool {
+        self == other
+    }
     /// <https://html.spec.whatwg.org/multipage/#ascii-serialisation-of-an-origin>
     pub fn ascii_serialization(&self) -> String {
         match *self {
@@ -93,6 +103,54 @@ impl Origin {
         }
     }
 }
+/// Serializes this origin as its ASCII serialization; opaque origins
+/// serialize as `"null"`.
+///
+/// This implementation is only available if the `serde` Cargo feature is enabled.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Origin {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.ascii_serialization())
+    }
+}
+/// Deserializes a tuple origin from a URL string via `Url::parse`.
+///
+/// `"null"` and URLs with an opaque origin fail to deserialize, since an
+/// opaque origin is only equal to itself and cannot be reconstructed.
+///
+/// This implementation is only available if the `serde` Cargo feature is enabled.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Origin {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::{Error, Visitor};
+        struct OriginVisitor;
+        impl<'de> Visitor<'de> for OriginVisitor {
+            type Value = Origin;
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a URL string with a tuple origin")
+            }
+            fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                let url = Url::parse(s).map_err(Error::custom)?;
+                match url_origin(&url) {
+                    origin @ Origin::Tuple(..) => Ok(origin),
+                    Origin::Opaque(_) => {
+                        Err(Error::custom("cannot deserialize an opaque origin"))
+                    }
+                }
+            }
+        }
+        deserializer.deserialize_str(OriginVisitor)
+    }
+}
 /// Opaque identifier for URLs that have file or other schemes
 #[derive(Eq, PartialEq, Hash, Clone, Debug)]
 pub struct OpaqueOrigin(usize);
@@ -6,11 +6,11 @@ use crate::Url;
 use form_urlencoded::EncodingOverride;
 use percent_encoding::{percent_encode, utf8_percent_encode, AsciiSet, CONTROLS};
 /// https://url.spec.whatwg.org/#fragment-percent-encode-set
-const FRAGMENT: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'<').add(b'>').add(b'`');
+pub const FRAGMENT: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'<').add(b'>').add(b'`');
 /// https://url.spec.whatwg.org/#path-percent-encode-set
-const PATH: &AsciiSet = &FRAGMENT.add(b'#').add(b'?').add(b'{').add(b'}');
+pub const PATH: &AsciiSet = &FRAGMENT.add(b'#').add(b'?').add(b'{').add(b'}');
 /// https://url.spec.whatwg.org/#userinfo-percent-encode-set
-pub(crate) const USERINFO: &AsciiSet = &PATH
+pub const USERINFO: &AsciiSet = &PATH
     .add(b'/')
     .add(b':')
     .add(b';')
@@ -21,10 +21,10 @@ pub(crate) const USERINFO: &AsciiSet = &PATH
     .add(b']')
     .add(b'^')
     .add(b'|');
-pub(crate) const PATH_SEGMENT: &AsciiSet = &PATH.add(b'/').add(b'%');
+pub const PATH_SEGMENT: &AsciiSet = &PATH.add(b'/').add(b'%');
 pub(crate) const SPECIAL_PATH_SEGMENT: &AsciiSet = &PATH_SEGMENT.add(b'\\');
-const QUERY: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'#').add(b'<').add(b'>');
-const SPECIAL_QUERY: &AsciiSet = &QUERY.add(b'\'');
+pub const QUERY: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'#').add(b'<').add(b'>');
+pub const SPECIAL_QUERY: &AsciiSet = &QUERY.add(b'\'');
 pub type ParseResult<T> = Result<T, ParseError>;
 macro_rules! simple_enum_error {
     ($($name:ident => $description:expr,)+) => {
@@ -48,12 +48,129 @@ simple_enum_error! {
     RelativeUrlWithCannotBeABaseBase => "relative URL with a cannot-be-a-base base",
     SetHostOnCannotBeABaseUrl => "a cannot-be-a-base URL doesn’t have a host to set",
     Overflow => "URLs more than 4 GB are not supported",
+    InputTooLong => "input longer than the configured maximum length",
+    SchemeNotAllowed => "scheme is not in the configured allowed set",
+    ForbiddenControlCharacter => "forbidden control character",
+    InvalidScheme => "invalid or incompatible scheme",
+    BackslashInPath => "backslash in the path of a non-special URL",
+    InvalidRawComponent => "raw component is not in fully encoded form",
 }
 impl From<::idna::Errors> for ParseError {
     fn from(_: ::idna::Errors) -> ParseError {
         ParseError::IdnaError
     }
 }
+/// A `ParseError` together with the position where parsing failed,
+/// as returned by `Url::parse_detailed`.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct DetailedParseError {
+    kind: ParseError,
+    offset: usize,
+    fragment: String,
+}
+impl DetailedParseError {
+    /// The kind of error, as `Url::parse` would have returned it.
+    pub fn kind(&self) -> ParseError {
+        self.kind
+    }
+    /// The byte offset into the original input where parsing failed.
+    ///
+    /// For errors that concern a component (e.g. the host), this is the
+    /// offset of that component. For errors without a meaningful position
+    /// (e.g. a relative URL without a base), this is `0`.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+    /// The offending part of the input, or the empty string if the error
+    /// has no meaningful position.
+    pub fn fragment(&self) -> &str {
+        &self.fragment
+    }
+}
+impl fmt::Display for DetailedParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset)?;
+        if !self.fragment.is_empty() {
+            write!(f, ": {:?}", self.fragment)?;
+        }
+        Ok(())
+    }
+}
+impl Error for DetailedParseError {}
+impl From<DetailedParseError> for ParseError {
+    fn from(error: DetailedParseError) -> ParseError {
+        error.kind
+    }
+}
+/// Best-effort location of `kind` within `input`, re-deriving the component
+/// boundaries the same way the parser does rather than threading positions
+/// through every error return. Offsets are relative to the original,
+/// untrimmed input; ignored tabs and newlines inside the input may shift
+/// them slightly.
+pub(crate) fn locate_error(input: &str, kind: ParseError) -> DetailedParseError {
+    let trimmed = input.trim_matches(c0_control_or_space);
+    let base = trimmed.as_ptr() as usize - input.as_ptr() as usize;
+    let (offset, fragment) = match kind {
+        ParseError::EmptyHost
+        | ParseError::IdnaError
+        | ParseError::InvalidIpv4Address
+        | ParseError::InvalidIpv6Address
+        | ParseError::InvalidDomainCharacter
+        | ParseError::InvalidPort => locate_in_authority(trimmed, kind),
+        _ => (0, ""),
+    };
+    DetailedParseError {
+        kind,
+        offset: base + offset,
+        fragment: fragment.to_owned(),
+    }
+}
+fn locate_in_authority(input: &str, kind: ParseError) -> (usize, &str) {
+    let mut authority_start = match input.find(':') {
+        Some(i) => i + 1,
+        None => return (0, ""),
+    };
+    while input[authority_start..].starts_with('/')
+        || input[authority_start..].starts_with('\\')
+    {
+        authority_start += 1;
+    }
+    let authority_end = input[authority_start..]
+        .find(|c| matches!(c, '/' | '\\' | '?' | '#'))
+        .map(|i| authority_start + i)
+        .unwrap_or_else(|| input.len());
+    let authority = &input[authority_start..authority_end];
+    let host_start = authority_start + authority.rfind('@').map(|i| i + 1).unwrap_or(0);
+    let host_and_port = &input[host_start..authority_end];
+    // The port starts after the first colon outside an IPv6 bracket pair
+    let host_len = if host_and_port.starts_with('[') {
+        host_and_port.find(']').map(|i| i + 1).unwrap_or_else(|| host_and_port.len())
+    } else {
+        host_and_port.find(':').unwrap_or_else(|| host_and_port.len())
+    };
+    if kind == ParseError::InvalidPort {
+        if host_and_port[host_len..].starts_with(':') {
+            let port_start = host_start + host_len + 1;
+            return (port_start, &input[port_start..authority_end]);
+        }
+        return (host_start, host_and_port);
+    }
+    let host = &host_and_port[..host_len];
+    if let ParseError::IdnaError | ParseError::InvalidDomainCharacter = kind {
+        // Point at the first forbidden host code point, if there is one
+        let forbidden = |c: char| {
+            matches!(
+                c, '\0' ..= '\x1f' | ' ' | '"' | '#' | '%' | '/' | ':' | '<' | '>' | '?'
+                | '@' | '[' | '\\' | ']' | '^' | '|' | '\x7f'
+            )
+        };
+        if let Some(i) = host.find(forbidden) {
+            let c_len = host[i..].chars().next().map(char::len_utf8).unwrap_or(0);
+            return (host_start + i, &host[i..i + c_len]);
+        }
+    }
+    (host_start, host)
+}
 macro_rules! syntax_violation_enum {
     ($($name:ident => $description:expr,)+) => {
         #[doc = " Non-fatal syntax violations that can occur during parsing."] #[doc =
@@ -1401,6 +1518,23 @@ fn is_url_code_point(c: char) -> bool {
 fn c0_control_or_space(ch: char) -> bool {
     ch <= ' '
 }
+/// Cheaply extract the scheme of `input` as `parse_scheme` would see it
+/// (trimmed, tabs and newlines removed, lowercased), without touching the
+/// rest of the URL. Returns `None` if the input has no valid scheme.
+pub(crate) fn extract_scheme(input: &str) -> Option<String> {
+    let input = input.trim_matches(c0_control_or_space);
+    let mut scheme = String::new();
+    for c in input.chars() {
+        match c {
+            '\t' | '\n' | '\r' => continue,
+            ':' if !scheme.is_empty() => return Some(scheme),
+            'a'..='z' | 'A'..='Z' => scheme.push(c.to_ascii_lowercase()),
+            '0'..='9' | '+' | '-' | '.' if !scheme.is_empty() => scheme.push(c),
+            _ => return None,
+        }
+    }
+    None
+}
 /// https://infra.spec.whatwg.org/#ascii-tab-or-newline
 #[inline]
 fn ascii_tab_or_new_line(ch: char) -> bool {
@@ -126,6 +126,48 @@ impl<'a> PathSegmentsMut<'a> {
         self.url.serialization.truncate(self.after_first_slash + last_slash);
         self
     }
+    /// Remove the last segment of this URL’s path and return it,
+    /// still percent-encoded.
+    ///
+    /// A trailing slash yields an empty segment. Returns `None`, leaving the
+    /// URL unchanged, when the path is just `/`, so the initial slash is
+    /// never removed.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use url::Url;
+    /// # use std::error::Error;
+    ///
+    /// # fn run() -> Result<(), Box<dyn Error>> {
+    /// let mut url = Url::parse("https://example.net/a/100%25.png")?;
+    /// {
+    ///     let mut segments = url.path_segments_mut().map_err(|_| "cannot be base")?;
+    ///     assert_eq!(segments.pop_segment(), Some("100%25.png".to_string()));
+    ///     assert_eq!(segments.pop_segment(), Some("a".to_string()));
+    ///     assert_eq!(segments.pop_segment(), None);
+    /// }
+    /// assert_eq!(url.as_str(), "https://example.net/");
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn pop_segment(&mut self) -> Option<String> {
+        if self.url.serialization.len() <= self.after_first_slash {
+            return None;
+        }
+        let last_slash = self
+            .url
+            .serialization[self.after_first_slash..]
+            .rfind('/')
+            .unwrap_or(0);
+        let segment_start = self.after_first_slash + last_slash;
+        let segment = self.url.serialization[segment_start..]
+            .trim_start_matches('/')
+            .to_owned();
+        self.url.serialization.truncate(segment_start);
+        Some(segment)
+    }
     /// Append the given segment at the end of this URL’s path.
     ///
     /// See the documentation for `.extend()`.
@@ -7,7 +7,7 @@
 // except according to those terms.
 
 use crate::Url;
-use std::ops::{Index, Range, RangeFrom, RangeFull, RangeTo};
+use std::ops::{Index, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo};
 
 impl Index<RangeFull> for Url {
     type Output = str;
@@ -37,6 +37,28 @@ impl Index<Range<Position>> for Url {
     }
 }
 
+/// Like the exclusive range, but the slice extends through the delimiter
+/// byte (`:`, `@`, `?` or `#`) that immediately follows the end position,
+/// if there is one. For example `&url[Position::BeforeScheme..=Position::AfterScheme]`
+/// is `"http:"` where the exclusive range gives `"http"`, and
+/// `&url[Position::BeforeUsername..=Position::AfterPassword]` includes
+/// the trailing `@`. When the end position is not followed by a delimiter
+/// the two ranges are equivalent.
+impl Index<RangeInclusive<Position>> for Url {
+    type Output = str;
+    fn index(&self, range: RangeInclusive<Position>) -> &str {
+        let start = self.index(*range.start());
+        let mut end = self.index(*range.end());
+        if matches!(
+            self.serialization.as_bytes().get(end),
+            Some(b':') | Some(b'@') | Some(b'?') | Some(b'#')
+        ) {
+            end += 1;
+        }
+        &self.serialization[start..end]
+    }
+}
+
 /// Indicates a position within a URL based on its components.
 ///
 /// A range of positions can be used for slicing `Url`:
@@ -97,7 +119,77 @@ pub enum Position {
     AfterFragment,
 }
 
+impl Position {
+    /// All positions, in the order in which they occur within a URL.
+    pub const ALL: [Position; 16] = [
+        Position::BeforeScheme,
+        Position::AfterScheme,
+        Position::BeforeUsername,
+        Position::AfterUsername,
+        Position::BeforePassword,
+        Position::AfterPassword,
+        Position::BeforeHost,
+        Position::AfterHost,
+        Position::BeforePort,
+        Position::AfterPort,
+        Position::BeforePath,
+        Position::AfterPath,
+        Position::BeforeQuery,
+        Position::AfterQuery,
+        Position::BeforeFragment,
+        Position::AfterFragment,
+    ];
+}
+
+/// A named URL component, for slicing out one component with
+/// [`Url::component_str`] without spelling out the [`Position`] pair
+/// that delimits it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UrlComponent {
+    Scheme,
+    Username,
+    Password,
+    Host,
+    Port,
+    Path,
+    Query,
+    Fragment,
+}
+
 impl Url {
+    /// Returns the given component of this URL as a string slice,
+    /// without the delimiters that separate it from its neighbors.
+    ///
+    /// This is equivalent to slicing with the `Before…`/`After…`
+    /// [`Position`] pair for the component, so an absent component is the
+    /// empty string, matching the dedicated accessors where they exist
+    /// (e.g. `url.component_str(UrlComponent::Query)` is `url.query()`
+    /// or `""`).
+    ///
+    /// ```rust
+    /// use url::{Url, UrlComponent};
+    ///
+    /// let url = Url::parse("https://user@example.com/path?q=1#top").unwrap();
+    /// assert_eq!(url.component_str(UrlComponent::Scheme), "https");
+    /// assert_eq!(url.component_str(UrlComponent::Username), "user");
+    /// assert_eq!(url.component_str(UrlComponent::Password), "");
+    /// assert_eq!(url.component_str(UrlComponent::Query), "q=1");
+    /// assert_eq!(url.component_str(UrlComponent::Fragment), "top");
+    /// ```
+    pub fn component_str(&self, component: UrlComponent) -> &str {
+        let (start, end) = match component {
+            UrlComponent::Scheme => (Position::BeforeScheme, Position::AfterScheme),
+            UrlComponent::Username => (Position::BeforeUsername, Position::AfterUsername),
+            UrlComponent::Password => (Position::BeforePassword, Position::AfterPassword),
+            UrlComponent::Host => (Position::BeforeHost, Position::AfterHost),
+            UrlComponent::Port => (Position::BeforePort, Position::AfterPort),
+            UrlComponent::Path => (Position::BeforePath, Position::AfterPath),
+            UrlComponent::Query => (Position::BeforeQuery, Position::AfterQuery),
+            UrlComponent::Fragment => (Position::BeforeFragment, Position::AfterFragment),
+        };
+        &self[start..end]
+    }
+
     #[inline]
     fn index(&self, position: Position) -> usize {
         match position {
@@ -121,7 +213,12 @@ impl Url {
                 if self.has_authority() && self.byte_at(self.username_end) == b':' {
                     self.username_end as usize + ":".len()
                 } else {
-                    debug_assert!(self.username_end == self.host_start);
+                    // No password: either there is no userinfo at all, or a
+                    // username directly followed by the `@` delimiter.
+                    debug_assert!(
+                        self.username_end == self.host_start
+                            || self.byte_at(self.username_end) == b'@'
+                    );
                     self.username_end as usize
                 }
             }
@@ -130,6 +227,10 @@ impl Url {
                 if self.has_authority() && self.byte_at(self.username_end) == b':' {
                     debug_assert!(self.byte_at(self.host_start - "@".len() as u32) == b'@');
                     self.host_start as usize - "@".len()
+                } else if self.has_authority() && self.byte_at(self.username_end) == b'@' {
+                    // Username without password: the empty password sits
+                    // before the `@` delimiter.
+                    self.username_end as usize
                 } else {
                     debug_assert!(self.username_end == self.host_start);
                     self.host_start as usize
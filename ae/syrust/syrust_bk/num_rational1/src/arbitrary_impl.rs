@@ -0,0 +1,162 @@
+use crate::Ratio;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use num_integer::Integer;
+use num_traits::Bounded;
+
+/// Generates well-formed ratios: the denominator is never zero (a zero draw
+/// is replaced by one), but the ratio is not necessarily reduced. Use
+/// [`Ratio::arbitrary_reduced`] when the reduced invariant is required, or
+/// [`Ratio::arbitrary_raw`] to opt into invalid zero denominators.
+impl<'a, T> Arbitrary<'a> for Ratio<T>
+where
+    T: Arbitrary<'a> + Clone + Integer,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let numer = T::arbitrary(u)?;
+        let mut denom = T::arbitrary(u)?;
+        if denom.is_zero() {
+            denom = T::one();
+        }
+        Ok(Ratio::new_raw(numer, denom))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(T::size_hint(depth), T::size_hint(depth))
+    }
+}
+
+impl<T: Clone + Integer> Ratio<T> {
+    /// Generates an arbitrary ratio already in lowest terms with a positive
+    /// denominator.
+    ///
+    /// Note that reducing can panic on two's-complement extremes (e.g. a
+    /// `T::MIN` denominator), so the sign normalization is skipped for
+    /// draws it cannot represent.
+    pub fn arbitrary_reduced<'a>(u: &mut Unstructured<'a>) -> Result<Ratio<T>>
+    where
+        T: Arbitrary<'a> + Bounded,
+    {
+        let mut ratio: Ratio<T> = Arbitrary::arbitrary(u)?;
+        if ratio.denom == T::min_value() {
+            ratio.denom = T::one();
+        }
+        if ratio.numer == T::min_value() && ratio.denom < T::zero() {
+            ratio.numer = ratio.numer.clone() + T::one();
+        }
+        ratio.reduce();
+        Ok(ratio)
+    }
+
+    /// Generates a completely unchecked ratio, including ones with a zero
+    /// denominator.
+    ///
+    /// This is opt-in for fuzzers that want to exercise the panicking edge
+    /// cases; most downstream code should use the `Arbitrary` impl instead.
+    pub fn arbitrary_raw<'a>(u: &mut Unstructured<'a>) -> Result<Ratio<T>>
+    where
+        T: Arbitrary<'a>,
+    {
+        Ok(Ratio::new_raw(T::arbitrary(u)?, T::arbitrary(u)?))
+    }
+
+    /// Generates ratios biased towards pathological cases: denominator one,
+    /// zero numerators, components near `T::MAX`/`T::MIN`, and unreduced
+    /// multiples of small fractions like `2/4` for `1/2`.
+    pub fn arbitrary_pathological<'a>(u: &mut Unstructured<'a>) -> Result<Ratio<T>>
+    where
+        T: Arbitrary<'a> + Bounded,
+    {
+        // Builds a small value in `1..=8` from one byte of input; products of
+        // two of these stay representable even in i8.
+        fn small<T: Clone + Integer>(u: &mut Unstructured<'_>) -> Result<T> {
+            let n = u8::arbitrary(u)? % 8;
+            let mut value = T::one();
+            for _ in 0..n {
+                value = value + T::one();
+            }
+            Ok(value)
+        }
+
+        Ok(match u.int_in_range(0u8..=5)? {
+            0 => Ratio::new_raw(T::arbitrary(u)?, T::one()),
+            1 => Ratio::new_raw(T::zero(), small(u)?),
+            2 => Ratio::new_raw(T::max_value() - small(u)?, small(u)?),
+            3 => Ratio::new_raw(T::min_value() + small(u)?, small(u)?),
+            4 => {
+                // An unreduced multiple of a small fraction, e.g. 2/4
+                let factor: T = small(u)?;
+                Ratio::new_raw(small::<T>(u)? * factor.clone(), small::<T>(u)? * factor)
+            }
+            _ => Arbitrary::arbitrary(u)?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod test {
+    use crate::Ratio;
+    use arbitrary::{Arbitrary, Unstructured};
+    use num_integer::Integer;
+    use num_traits::{CheckedAdd, Zero};
+
+    /// Deterministic pseudo-random input for `Unstructured`.
+    fn fuzz_data(len: usize) -> std::vec::Vec<u8> {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (state >> 56) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_generated_ratios_uphold_invariants() {
+        let data = fuzz_data(1 << 16);
+        let mut u = Unstructured::new(&data);
+        let mut previous: Option<Ratio<i32>> = None;
+        for _ in 0..2000 {
+            let ratio = match u8::arbitrary(&mut u).unwrap() % 3 {
+                0 => Ratio::<i32>::arbitrary(&mut u).unwrap(),
+                1 => Ratio::<i32>::arbitrary_pathological(&mut u).unwrap(),
+                _ => Ratio::<i32>::arbitrary_reduced(&mut u).unwrap(),
+            };
+            assert!(!ratio.denom().is_zero());
+
+            // `reduce` negates both parts for negative denominators, which
+            // overflows on the two's-complement extremes; those inputs are
+            // valid for `cmp` and the checked ops but not for `reduce`.
+            let reducible = !(*ratio.denom() < 0
+                && (*ratio.numer() == i32::MIN || *ratio.denom() == i32::MIN));
+            if reducible {
+                let reduced = ratio.reduced();
+                assert!(*reduced.denom() > 0);
+                assert_eq!(reduced.numer().gcd(reduced.denom()), 1);
+                assert_eq!(reduced, ratio);
+            }
+
+            if let Some(previous) = previous {
+                // cmp must be total and agree with checked addition
+                let _ = ratio.cmp(&previous);
+                if let Some(sum) = ratio.checked_add(&previous) {
+                    assert!(*sum.denom() > 0);
+                }
+            }
+            previous = Some(ratio);
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_raw_can_produce_zero_denominator() {
+        let data = fuzz_data(1 << 14);
+        let mut u = Unstructured::new(&data);
+        let mut saw_zero_denom = false;
+        for _ in 0..2000 {
+            let ratio = Ratio::<i8>::arbitrary_raw(&mut u).unwrap();
+            saw_zero_denom |= ratio.denom().is_zero();
+        }
+        assert!(saw_zero_denom);
+    }
+}
@@ -15,24 +15,37 @@
 //! The `num-rational` crate is tested for rustc 1.31 and greater.
 
 #![doc(html_root_url = "https://docs.rs/num-rational/0.3")]
-//#![no_std]
+#![no_std]
 // Ratio ops often use other "suspicious" ops
 #![allow(clippy::suspicious_arithmetic_impl)]
 #![allow(clippy::suspicious_op_assign_impl)]
 
-//#[cfg(feature = "std")]
+#[cfg(feature = "std")]
 #[macro_use]
 extern crate std;
 
+// `String` and `Vec` come from `alloc` so the `BigRational` decimal
+// rendering and parsing also works without `std` (num-bigint requires
+// alloc anyway).
+#[cfg(any(feature = "std", feature = "num-bigint"))]
+extern crate alloc;
+
 use core::cmp;
+use core::convert::TryFrom;
 use core::fmt;
 use core::fmt::{Binary, Display, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex};
 use core::hash::{Hash, Hasher};
+use core::mem;
 use core::ops::{Add, Div, Mul, Neg, Rem, ShlAssign, Sub};
 use core::str::FromStr;
 #[cfg(feature = "std")]
 use std::error::Error;
 
+#[cfg(any(feature = "std", feature = "num-bigint"))]
+use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+
 #[cfg(feature = "num-bigint")]
 use num_bigint::{BigInt, BigUint, Sign, ToBigInt};
 
@@ -40,12 +53,23 @@ use num_integer::Integer;
 use num_traits::float::FloatCore;
 use num_traits::ToPrimitive;
 use num_traits::{
-    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Inv, Num, NumCast, One,
-    Pow, Signed, Zero,
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Euclid, FromPrimitive, Inv, MulAdd,
+    MulAddAssign, Num, NumCast, One, Pow, Signed, Zero,
 };
 
 mod pow;
 
+#[cfg(feature = "rand")]
+mod rand_impl;
+#[cfg(feature = "rand")]
+pub use crate::rand_impl::UniformRatio;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+
+#[cfg(feature = "proptest")]
+pub mod proptest_impl;
+
 /// Represents the ratio between two numbers.
 #[derive(Copy, Clone, Debug)]
 #[allow(missing_docs)]
@@ -89,6 +113,21 @@ impl<T> Ratio<T> {
     pub const fn denom(&self) -> &T {
         &self.denom
     }
+
+    /// Consumes the `Ratio`, returning `(numer, denom)`.
+    ///
+    /// This is a named alternative to the `Into<(T, T)>` impl, so generic
+    /// code does not need the tuple conversion trait in scope.
+    #[inline]
+    pub fn into_raw(self) -> (T, T) {
+        (self.numer, self.denom)
+    }
+}
+
+/// Shared panic for zero denominators, naming the operation that hit it.
+#[inline(never)]
+fn denom_zero_panic(op: &str) -> ! {
+    panic!("denominator == 0 in {}", op)
 }
 
 impl<T: Clone + Integer> Ratio<T> {
@@ -102,12 +141,128 @@ impl<T: Clone + Integer> Ratio<T> {
         ret
     }
 
+    /// Creates a new `Ratio`, reducing like [`Ratio::new`] but returning
+    /// `None` instead of panicking when `denom` is zero.
+    #[inline]
+    pub fn try_new(numer: T, denom: T) -> Option<Ratio<T>> {
+        if denom.is_zero() {
+            None
+        } else {
+            Some(Ratio::new(numer, denom))
+        }
+    }
+
+    /// Alias for [`Ratio::try_new`] following the `checked_*` naming of the
+    /// arithmetic methods.
+    #[inline]
+    pub fn checked_new(numer: T, denom: T) -> Option<Ratio<T>> {
+        Ratio::try_new(numer, denom)
+    }
+
+    /// Creates a new reduced `Ratio`, returning `None` when the denominator
+    /// is zero or when keeping the denominator positive would overflow.
+    ///
+    /// [`Ratio::new`] normalizes the sign by negating both components, which
+    /// panics for two's-complement `T` when the reduced numerator or
+    /// denominator is `T::MIN` (e.g. `(i32::MIN, -1)`). This uses the same
+    /// checked negation as `CheckedDiv` instead.
+    #[inline]
+    pub fn checked_from_pair(numer: T, denom: T) -> Option<Ratio<T>>
+    where
+        T: CheckedMul,
+    {
+        // Manual `reduce()`, avoiding sharp edges
+        if denom.is_zero() {
+            None
+        } else if numer.is_zero() {
+            Some(Self::zero())
+        } else if numer == denom {
+            Some(Self::one())
+        } else {
+            let g = numer.gcd(&denom);
+            let numer = numer / g.clone();
+            let denom = denom / g;
+            Some(if denom < T::zero() {
+                // We need to keep denom positive, but 2's-complement MIN may
+                // overflow negation -- instead we can check multiplying -1.
+                let n1 = T::zero() - T::one();
+                Ratio::new_raw(numer.checked_mul(&n1)?, denom.checked_mul(&n1)?)
+            } else {
+                Ratio::new_raw(numer, denom)
+            })
+        }
+    }
+
     /// Creates a `Ratio` representing the integer `t`.
     #[inline]
     pub fn from_integer(t: T) -> Ratio<T> {
         Ratio::new_raw(t, One::one())
     }
 
+    /// Creates the exact reduced value of `mantissa * 10^exponent`,
+    /// without any float round-trip.
+    ///
+    /// `10^|exponent|` is built by repeated multiplication, so a large
+    /// exponent overflows for fixed-size `T` just like the equivalent
+    /// integer arithmetic would.
+    #[inline]
+    pub fn from_scientific_parts(mantissa: T, exponent: i32) -> Ratio<T> {
+        if exponent >= 0 {
+            Ratio::from_integer(mantissa * Ratio::pow_of_ten(exponent as u32))
+        } else {
+            // wrapping_neg maps i32::MIN to 2^31 after the cast
+            Ratio::new(mantissa, Ratio::pow_of_ten(exponent.wrapping_neg() as u32))
+        }
+    }
+
+    /// Returns `1/2`.
+    #[inline]
+    pub fn half() -> Ratio<T> {
+        Ratio::new_raw(T::one(), T::one() + T::one())
+    }
+
+    /// Returns `1/3`.
+    #[inline]
+    pub fn third() -> Ratio<T> {
+        Ratio::new_raw(T::one(), T::one() + T::one() + T::one())
+    }
+
+    /// Returns `2/3`.
+    #[inline]
+    pub fn two_thirds() -> Ratio<T> {
+        Ratio::new_raw(T::one() + T::one(), T::one() + T::one() + T::one())
+    }
+
+    /// Returns `1/4`.
+    #[inline]
+    pub fn quarter() -> Ratio<T> {
+        let two = T::one() + T::one();
+        Ratio::new_raw(T::one(), two.clone() * two)
+    }
+
+    /// Returns the unit fraction `1/n`, or `None` if `n` is zero.
+    #[inline]
+    pub fn unit_fraction(n: T) -> Option<Ratio<T>> {
+        Ratio::try_new(T::one(), n)
+    }
+
+    /// Returns `p` percent, i.e. `p/100` reduced.
+    #[inline]
+    pub fn percent(p: T) -> Ratio<T> {
+        Ratio::new(p, Ratio::pow_of_ten(2))
+    }
+
+    /// `10^exp` by repeated multiplication, for `from_scientific_parts`.
+    fn pow_of_ten(exp: u32) -> T {
+        let two = T::one() + T::one();
+        let ten = two.clone() * two.clone() * two.clone() + two;
+        let mut result = T::one();
+        for _ in 0..exp {
+            result = result * ten.clone();
+        }
+        result
+    }
+
     /// Converts to an integer, rounding towards zero.
     #[inline]
     pub fn to_integer(&self) -> T {
@@ -120,12 +275,212 @@ impl<T: Clone + Integer> Ratio<T> {
         self.denom.is_one()
     }
 
+    /// Scales to an equal ratio over `target`, returning `None` unless the
+    /// current denominator divides `target` exactly.
+    ///
+    /// The result is deliberately left unreduced so the target denominator
+    /// is preserved, e.g. for aligning fractions to a common denominator.
+    #[inline]
+    pub fn scale_to_denominator(&self, target: T) -> Option<Ratio<T>> {
+        if target.is_zero() || !(target.clone() % self.denom.clone()).is_zero() {
+            return None;
+        }
+        let numer = self.numer.clone() * (target.clone() / self.denom.clone());
+        Some(Ratio::new_raw(numer, target))
+    }
+
+    /// Returns the absolute value, without requiring `T: Signed`.
+    ///
+    /// For unsigned `T` this is simply a copy; negative components of a
+    /// signed `Ratio` are negated via `T::zero() - x`, as in `reduce`.
+    #[inline]
+    pub fn abs_ratio(&self) -> Ratio<T> {
+        let numer = if self.numer < T::zero() {
+            T::zero() - self.numer.clone()
+        } else {
+            self.numer.clone()
+        };
+        let denom = if self.denom < T::zero() {
+            T::zero() - self.denom.clone()
+        } else {
+            self.denom.clone()
+        };
+        Ratio::new_raw(numer, denom)
+    }
+
+    /// Returns true if the value is strictly less than zero, without
+    /// requiring `T: Signed`. Always false for unsigned `T`.
+    #[inline]
+    pub fn is_negative_ratio(&self) -> bool {
+        !self.numer.is_zero() && ((self.numer < T::zero()) != (self.denom < T::zero()))
+    }
+
+    /// Returns true if the value is strictly greater than zero, without
+    /// requiring `T: Signed`.
+    #[inline]
+    pub fn is_positive_ratio(&self) -> bool {
+        !self.numer.is_zero() && ((self.numer < T::zero()) == (self.denom < T::zero()))
+    }
+
+    /// Returns the sign of the ratio as a bare integer: `T::one()` for
+    /// positive values, `-T::one()` for negative values and `T::zero()`
+    /// for zero, e.g. for branching without building the `Ratio` that
+    /// [`signum`](Signed::signum) returns. Non-canonical negative
+    /// denominators are handled, as in the `Signed` implementation.
+    #[inline]
+    pub fn signum_int(&self) -> T
+    where
+        T: Signed,
+    {
+        if self.is_positive_ratio() {
+            T::one()
+        } else if self.numer.is_zero() {
+            T::zero()
+        } else {
+            -T::one()
+        }
+    }
+
+    /// Returns true if self is already in lowest terms with a positive
+    /// denominator, i.e. if `reduce` would leave it unchanged.
+    ///
+    /// Only `new_raw` can produce values for which this is false.
+    #[inline]
+    pub fn is_reduced(&self) -> bool {
+        self.denom > T::zero() && self.numer.gcd(&self.denom).is_one()
+    }
+
+    /// Returns a reduced copy like [`Ratio::reduced`], returning `None`
+    /// instead of panicking when the denominator is zero.
+    #[inline]
+    pub fn try_reduced(&self) -> Option<Ratio<T>> {
+        if self.denom.is_zero() {
+            None
+        } else {
+            Some(self.reduced())
+        }
+    }
+
+    /// Puts self into lowest terms like `reduce`, but reports failures
+    /// instead of panicking, leaving `self` unchanged on `Err`.
+    ///
+    /// Fails with [`RatioErrorKind::ZeroDenominator`] for a zero
+    /// denominator, and with [`RatioErrorKind::NegationOverflow`] when the
+    /// sign normalization would overflow (a reduced `T::MIN` component for
+    /// two's-complement types). Useful for defensively normalizing
+    /// `new_raw`-constructed ratios.
+    pub fn try_reduce(&mut self) -> Result<(), RatioErrorKind>
+    where
+        T: CheckedMul,
+    {
+        if self.denom.is_zero() {
+            return Err(RatioErrorKind::ZeroDenominator);
+        }
+        if self.numer.is_zero() {
+            self.denom.set_one();
+            return Ok(());
+        }
+        if self.numer == self.denom {
+            self.set_one();
+            return Ok(());
+        }
+        let g: T = self.numer.gcd(&self.denom);
+        let numer = self.numer.clone() / g.clone();
+        let denom = self.denom.clone() / g;
+        // keep denom positive, checking the 2's-complement MIN negation
+        // by multiplying -1, as in `CheckedDiv`
+        if denom < T::zero() {
+            let n1 = T::zero() - T::one();
+            let numer = numer
+                .checked_mul(&n1)
+                .ok_or(RatioErrorKind::NegationOverflow)?;
+            let denom = denom
+                .checked_mul(&n1)
+                .ok_or(RatioErrorKind::NegationOverflow)?;
+            self.numer = numer;
+            self.denom = denom;
+        } else {
+            self.numer = numer;
+            self.denom = denom;
+        }
+        Ok(())
+    }
+
+    /// Returns an iterator over the continued fraction coefficients
+    /// `[a0; a1, a2, ...]` of self.
+    ///
+    /// This uses the floor-based convention, so for a negative ratio `a0`
+    /// may be negative but every following coefficient is positive. The
+    /// expansion always terminates since the value is rational. This is the
+    /// exact counterpart of the float-typed expansion in
+    /// `approximate_float`, which truncates against an error bound instead
+    /// of running to the end.
+    ///
+    /// **Panics if `denom` is zero.**
+    pub fn continued_fraction(&self) -> impl Iterator<Item = T> {
+        let reduced = self.reduced();
+        ContinuedFraction {
+            numer: reduced.numer,
+            denom: reduced.denom,
+        }
+    }
+
+    /// Rebuilds a ratio from continued fraction coefficients, folding with
+    /// checked arithmetic.
+    ///
+    /// Returns `None` for empty input and when a convergent overflows `T`.
+    /// This inverts [`Ratio::continued_fraction`] for any expansion it
+    /// produces; other coefficient sequences (with zero or negative tail
+    /// coefficients) are folded by the same convergent recurrence, with a
+    /// zero final denominator reported as `None`.
+    pub fn from_continued_fraction<I>(iter: I) -> Option<Ratio<T>>
+    where
+        T: CheckedAdd + CheckedMul,
+        I: IntoIterator<Item = T>,
+    {
+        // the standard convergent recurrence:
+        // h_n = a_n * h_{n-1} + h_{n-2}, k_n likewise
+        let mut h_prev = T::one();
+        let mut h_prev_prev = T::zero();
+        let mut k_prev = T::zero();
+        let mut k_prev_prev = T::one();
+        let mut any = false;
+        for a in iter {
+            any = true;
+            let h = a.checked_mul(&h_prev)?.checked_add(&h_prev_prev)?;
+            let k = a.checked_mul(&k_prev)?.checked_add(&k_prev_prev)?;
+            h_prev_prev = h_prev;
+            h_prev = h;
+            k_prev_prev = k_prev;
+            k_prev = k;
+        }
+        if any {
+            Ratio::checked_from_pair(h_prev, k_prev)
+        } else {
+            None
+        }
+    }
+
+    /// Like [`Ratio::new`], but names `op` in the zero-denominator panic so
+    /// the arithmetic impls can report which operation tripped it.
+    #[inline]
+    fn new_op(numer: T, denom: T, op: &str) -> Ratio<T> {
+        let mut ret = Ratio::new_raw(numer, denom);
+        ret.reduce_with(op);
+        ret
+    }
+
     /// Puts self into lowest terms, with `denom` > 0.
     ///
     /// **Panics if `denom` is zero.**
     fn reduce(&mut self) {
+        self.reduce_with("reduce");
+    }
+
+    /// `reduce`, naming `op` in the zero-denominator panic.
+    fn reduce_with(&mut self, op: &str) {
         if self.denom.is_zero() {
-            panic!("denominator == 0");
+            denom_zero_panic(op);
         }
         if self.numer.is_zero() {
             self.denom.set_one();
@@ -167,7 +522,10 @@ impl<T: Clone + Integer> Ratio<T> {
 
     /// Returns the reciprocal.
     ///
-    /// **Panics if the `Ratio` is zero.**
+    /// **Panics if the `Ratio` is zero.** For two's-complement types it also
+    /// panics if the numerator is `T::MIN`, since keeping the denominator
+    /// positive requires negating both parts. Use [`Ratio::checked_recip`]
+    /// to handle both cases without panicking.
     #[inline]
     pub fn recip(&self) -> Ratio<T> {
         self.clone().into_recip()
@@ -177,34 +535,148 @@ impl<T: Clone + Integer> Ratio<T> {
     fn into_recip(self) -> Ratio<T> {
         match self.numer.cmp(&T::zero()) {
             cmp::Ordering::Equal => panic!("division by zero"),
+            // The common positive case just swaps, without clones or negation
             cmp::Ordering::Greater => Ratio::new_raw(self.denom, self.numer),
             cmp::Ordering::Less => Ratio::new_raw(T::zero() - self.denom, T::zero() - self.numer),
         }
     }
 
+    /// Returns the reciprocal, or `None` if the `Ratio` is zero or if the
+    /// sign normalization would overflow (e.g. a `T::MIN` numerator for
+    /// two's-complement types).
+    #[inline]
+    pub fn checked_recip(&self) -> Option<Ratio<T>>
+    where
+        T: CheckedMul,
+    {
+        match self.numer.cmp(&T::zero()) {
+            cmp::Ordering::Equal => None,
+            cmp::Ordering::Greater => {
+                Some(Ratio::new_raw(self.denom.clone(), self.numer.clone()))
+            }
+            cmp::Ordering::Less => {
+                // 2's-complement MIN may overflow negation -- instead we can
+                // check multiplying -1, as in `CheckedDiv`.
+                let n1 = T::zero() - T::one();
+                Some(Ratio::new_raw(
+                    self.denom.checked_mul(&n1)?,
+                    self.numer.checked_mul(&n1)?,
+                ))
+            }
+        }
+    }
+
+    /// Returns the negation, or `None` if negating the numerator would
+    /// overflow (a `T::MIN` numerator for two's-complement types, which
+    /// makes `Neg` panic).
+    ///
+    /// Zero negates to zero; for unsigned types any other value is `None`,
+    /// since `-1` itself is not representable.
+    #[inline]
+    pub fn checked_neg(&self) -> Option<Ratio<T>>
+    where
+        T: CheckedMul + CheckedSub,
+    {
+        if self.numer.is_zero() {
+            return Some(self.clone());
+        }
+        // 2's-complement MIN may overflow negation -- instead we can
+        // check multiplying -1, as in `CheckedDiv`.
+        let n1 = T::zero().checked_sub(&T::one())?;
+        Some(Ratio::new_raw(
+            self.numer.checked_mul(&n1)?,
+            self.denom.clone(),
+        ))
+    }
+
     /// Rounds towards minus infinity.
     #[inline]
     pub fn floor(&self) -> Ratio<T> {
-        if *self < Zero::zero() {
+        Ratio::from_integer(self.floor_to_integer())
+    }
+
+    /// Rounds towards minus infinity, returning the bare integer.
+    #[inline]
+    pub fn floor_to_integer(&self) -> T {
+        if *self < Self::zero() {
             let one: T = One::one();
-            Ratio::from_integer(
-                (self.numer.clone() - self.denom.clone() + one) / self.denom.clone(),
-            )
+            (self.numer.clone() - self.denom.clone() + one) / self.denom.clone()
         } else {
-            Ratio::from_integer(self.numer.clone() / self.denom.clone())
+            self.numer.clone() / self.denom.clone()
         }
     }
 
     /// Rounds towards plus infinity.
     #[inline]
     pub fn ceil(&self) -> Ratio<T> {
-        if *self < Zero::zero() {
-            Ratio::from_integer(self.numer.clone() / self.denom.clone())
+        Ratio::from_integer(self.ceil_to_integer())
+    }
+
+    /// Rounds towards plus infinity, returning the bare integer.
+    #[inline]
+    pub fn ceil_to_integer(&self) -> T {
+        if *self < Self::zero() {
+            self.numer.clone() / self.denom.clone()
         } else {
             let one: T = One::one();
-            Ratio::from_integer(
-                (self.numer.clone() + self.denom.clone() - one) / self.denom.clone(),
-            )
+            (self.numer.clone() + self.denom.clone() - one) / self.denom.clone()
+        }
+    }
+
+    /// Converts to an integer with an explicit [`RoundingMode`], instead of
+    /// the implied truncation of [`to_integer`](Ratio::to_integer).
+    ///
+    /// **Panics on overflow** (e.g. flooring a value below `T::min_value()`
+    /// reached through a negative denominator) **or a zero denominator.**
+    /// Use [`Ratio::checked_to_integer_round`] to get `None` instead.
+    #[inline]
+    pub fn to_integer_round(&self, mode: RoundingMode) -> T
+    where
+        T: CheckedAdd + CheckedSub + CheckedDiv,
+    {
+        self.checked_to_integer_round(mode)
+            .expect("overflow in to_integer_round")
+    }
+
+    /// Converts to an integer with an explicit [`RoundingMode`], returning
+    /// `None` when the rounded value cannot be computed without overflow,
+    /// or when the denominator is zero.
+    ///
+    /// The half-way comparison is done with [`Ratio::cmp_abs`] against
+    /// `1/2`, so it never overflows even at the extremes of `T`.
+    pub fn checked_to_integer_round(&self, mode: RoundingMode) -> Option<T>
+    where
+        T: CheckedAdd + CheckedSub + CheckedDiv,
+    {
+        let quotient = self.numer.checked_div(&self.denom)?;
+        let remainder = self.numer.clone() - quotient.clone() * self.denom.clone();
+        if remainder.is_zero() {
+            return Some(quotient);
+        }
+        let negative = (self.numer < T::zero()) != (self.denom < T::zero());
+        let one = T::one();
+        let round_away = match mode {
+            RoundingMode::Truncate => false,
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceil => !negative,
+            RoundingMode::HalfUp | RoundingMode::HalfEven => {
+                let fractional = Ratio::new_raw(remainder, self.denom.clone());
+                let half = Ratio::new_raw(one.clone(), one.clone() + one.clone());
+                match fractional.cmp_abs(&half) {
+                    cmp::Ordering::Greater => true,
+                    cmp::Ordering::Less => false,
+                    cmp::Ordering::Equal => {
+                        mode == RoundingMode::HalfUp || quotient.is_odd()
+                    }
+                }
+            }
+        };
+        if !round_away {
+            Some(quotient)
+        } else if negative {
+            quotient.checked_sub(&one)
+        } else {
+            quotient.checked_add(&one)
         }
     }
 
@@ -221,53 +693,489 @@ impl<T: Clone + Integer> Ratio<T> {
             fractional = zero - fractional
         };
 
-        // The algorithm compares the unsigned fractional part with 1/2, that
-        // is, a/b >= 1/2, or a >= b/2. For odd denominators, we use
-        // a >= (b/2)+1. This avoids overflow issues.
-        let half_or_larger = if fractional.denom.is_even() {
-            fractional.numer >= fractional.denom / two
-        } else {
-            fractional.numer >= (fractional.denom / two) + one
+        // The algorithm compares the unsigned fractional part with 1/2, that
+        // is, a/b >= 1/2, or a >= b/2. For odd denominators, we use
+        // a >= (b/2)+1. This avoids overflow issues.
+        let half_or_larger = if fractional.denom.is_even() {
+            fractional.numer >= fractional.denom / two
+        } else {
+            fractional.numer >= (fractional.denom / two) + one
+        };
+
+        if half_or_larger {
+            let one: Ratio<T> = One::one();
+            if *self >= Self::zero() {
+                self.trunc() + one
+            } else {
+                self.trunc() - one
+            }
+        } else {
+            self.trunc()
+        }
+    }
+
+    /// Rounds to the nearest multiple of `1/2^k`, i.e. the nearest dyadic
+    /// rational with `k` fractional bits, with ties to even — the value a
+    /// fixed-point representation with `k` fractional bits would hold.
+    ///
+    /// Computed as `round(self * 2^k) / 2^k` in exact integer arithmetic;
+    /// the half-way comparison uses [`Ratio::cmp_abs`] against `1/2` so it
+    /// stays exact. The scaled numerator `numer * 2^k` must still fit in
+    /// `T`, otherwise the multiplication overflows like any other ratio
+    /// arithmetic.
+    pub fn round_to_power_of_two(&self, k: u32) -> Ratio<T> {
+        let one = T::one();
+        let two = one.clone() + one.clone();
+        let mut scale = one.clone();
+        for _ in 0..k {
+            scale = scale * two.clone();
+        }
+        let scaled_numer = self.numer.clone() * scale.clone();
+        let quotient = scaled_numer.div_floor(&self.denom);
+        let remainder = scaled_numer.mod_floor(&self.denom);
+        let rounded = if remainder.is_zero() {
+            quotient
+        } else {
+            // `fract` is in `[0, 1)` thanks to the floored division, so the
+            // tie decision is between `quotient` and `quotient + 1`
+            let fract = Ratio::new_raw(remainder, self.denom.clone());
+            let half = Ratio::new_raw(one.clone(), two);
+            match fract.cmp_abs(&half) {
+                cmp::Ordering::Less => quotient,
+                cmp::Ordering::Greater => quotient + one,
+                cmp::Ordering::Equal => {
+                    if quotient.is_even() {
+                        quotient
+                    } else {
+                        quotient + one
+                    }
+                }
+            }
+        };
+        Ratio::new(rounded, scale)
+    }
+
+    /// Rounds towards zero.
+    #[inline]
+    pub fn trunc(&self) -> Ratio<T> {
+        Ratio::from_integer(self.numer.clone() / self.denom.clone())
+    }
+
+    /// Returns the fractional part of a number, with division rounded towards zero.
+    ///
+    /// Satisfies `self == self.trunc() + self.fract()`.
+    #[inline]
+    pub fn fract(&self) -> Ratio<T> {
+        Ratio::new_raw(self.numer.clone() % self.denom.clone(), self.denom.clone())
+    }
+
+    /// Splits into the truncated integer part and the signed proper-fraction
+    /// remainder, so `(7/2)` becomes `(3, 1/2)` and `(-7/2)` becomes
+    /// `(-3, -1/2)`.
+    ///
+    /// Satisfies `self == integer + fraction`; both parts share the sign of
+    /// `self`. This is [`trunc`](Ratio::trunc) and [`fract`](Ratio::fract)
+    /// with the integer part returned as bare `T`, as a mixed number is
+    /// usually displayed.
+    #[inline]
+    pub fn as_mixed_number(&self) -> (T, Ratio<T>) {
+        (self.numer.clone() / self.denom.clone(), self.fract())
+    }
+
+    /// Compares the absolute values of `self` and `other`, e.g. for
+    /// sorting by magnitude, using the same overflow-safe algorithm as
+    /// `Ord::cmp` on the sign-flipped components.
+    ///
+    /// The sign of each value is derived from the numerator and
+    /// denominator signs, so non-canonical negative denominators are
+    /// handled. Like the negation operator, this panics for a numerator
+    /// of `T::min_value()` on primitive types.
+    pub fn cmp_abs(&self, other: &Ratio<T>) -> cmp::Ordering {
+        let negative = |r: &Ratio<T>| (r.numer < T::zero()) != (r.denom < T::zero());
+        let flip = |r: &Ratio<T>| Ratio::new_raw(T::zero() - r.numer.clone(), r.denom.clone());
+        match (negative(self), negative(other)) {
+            (false, false) => self.cmp(other),
+            (true, false) => flip(self).cmp(other),
+            (false, true) => self.cmp(&flip(other)),
+            (true, true) => flip(self).cmp(&flip(other)),
+        }
+    }
+
+    /// Raises the `Ratio` to the power of an exponent.
+    ///
+    /// A negative exponent takes the reciprocal of the positive power, so
+    /// this works for unsigned `T` as long as the value is nonzero; a zero
+    /// base with a negative exponent panics.
+    #[inline]
+    pub fn pow(&self, expon: i32) -> Ratio<T>
+    where
+        for<'a> &'a T: Pow<u32, Output = T>,
+    {
+        Pow::pow(self, expon)
+    }
+
+    /// Raises the `Ratio` to the power of an exponent, returning `None`
+    /// instead of panicking where [`pow`](Ratio::pow) would: a zero base
+    /// with a negative exponent, or an overflowing component power.
+    ///
+    /// Exponentiation is by repeated squaring on each component, like
+    /// [`pow_u64`](Ratio::pow_u64), so no `Pow` implementation on `T` is
+    /// needed. `i32::MIN` is handled like every other negative exponent.
+    pub fn checked_pow(&self, expon: i32) -> Option<Ratio<T>>
+    where
+        T: CheckedMul,
+    {
+        fn checked_int_pow<T: Clone + Integer + CheckedMul>(mut base: T, mut exp: u32) -> Option<T> {
+            let mut acc = T::one();
+            while exp > 0 {
+                if exp & 1 == 1 {
+                    acc = acc.checked_mul(&base)?;
+                }
+                exp >>= 1;
+                if exp > 0 {
+                    base = base.checked_mul(&base.clone())?;
+                }
+            }
+            Some(acc)
+        }
+        let expon_abs = expon.unsigned_abs();
+        if expon < 0 && self.is_zero() {
+            return None;
+        }
+        let raised = Ratio::new_raw(
+            checked_int_pow(self.numer.clone(), expon_abs)?,
+            checked_int_pow(self.denom.clone(), expon_abs)?,
+        );
+        if expon < 0 {
+            raised.checked_recip()
+        } else {
+            Some(raised)
+        }
+    }
+
+    /// Raises the `Ratio` to the power of a `u64` exponent by repeated
+    /// squaring, so exponents beyond the `i32` range of [`pow`](Ratio::pow)
+    /// work for `BigInt`-backed ratios.
+    ///
+    /// Unlike `pow` this requires no `Pow` implementation on `T`, only the
+    /// multiplication every `Integer` already has. The components are raised
+    /// separately, like the `Pow` implementations, so a value in lowest terms
+    /// stays in lowest terms.
+    pub fn pow_u64(&self, exp: u64) -> Ratio<T> {
+        fn int_pow<T: Clone + Integer>(mut base: T, mut exp: u64) -> T {
+            let mut acc = T::one();
+            while exp > 0 {
+                if exp & 1 == 1 {
+                    acc = acc * base.clone();
+                }
+                exp >>= 1;
+                if exp > 0 {
+                    base = base.clone() * base;
+                }
+            }
+            acc
+        }
+        Ratio::new_raw(
+            int_pow(self.numer.clone(), exp),
+            int_pow(self.denom.clone(), exp),
+        )
+    }
+
+    /// Raises the `Ratio` to the power of an `i64` exponent by repeated
+    /// squaring, taking the reciprocal for negative exponents.
+    ///
+    /// See [`pow_u64`](Ratio::pow_u64); a negative exponent on a zero value
+    /// panics, like [`pow`](Ratio::pow).
+    pub fn pow_i64(&self, exp: i64) -> Ratio<T> {
+        match exp.cmp(&0) {
+            cmp::Ordering::Equal => One::one(),
+            cmp::Ordering::Less => {
+                if self.is_zero() {
+                    panic!("zero cannot be raised to a negative power");
+                }
+                self.pow_u64(exp.unsigned_abs()).into_recip()
+            }
+            cmp::Ordering::Greater => self.pow_u64(exp as u64),
+        }
+    }
+
+    /// Returns the greatest common divisor of two ratios, i.e. the largest
+    /// rational number that divides both `self` and `other` an integral
+    /// number of times.
+    ///
+    /// For `a/b` and `c/d` in lowest terms this is `gcd(a, c) / lcm(b, d)`,
+    /// which avoids the overflow-prone cross-multiplications of the naive
+    /// `gcd(a*d, c*b) / (b*d)` formula. The result is non-negative, and
+    /// `gcd(0, x) == |x|` like the integer counterpart.
+    #[inline]
+    pub fn gcd(&self, other: &Ratio<T>) -> Ratio<T> {
+        Ratio::new(
+            self.numer.gcd(&other.numer),
+            self.denom.lcm(&other.denom),
+        )
+    }
+
+    /// Returns the least common multiple of two ratios, i.e. the smallest
+    /// non-negative rational number that both `self` and `other` divide an
+    /// integral number of times.
+    ///
+    /// For `a/b` and `c/d` in lowest terms this is `lcm(a, c) / gcd(b, d)`.
+    /// The result is non-negative, and the lcm with zero is zero.
+    #[inline]
+    pub fn lcm(&self, other: &Ratio<T>) -> Ratio<T> {
+        Ratio::new(
+            self.numer.lcm(&other.numer),
+            self.denom.gcd(&other.denom),
+        )
+    }
+
+    /// Returns the integer quotient of `self / other` rounded towards zero,
+    /// together with the rational remainder.
+    ///
+    /// Satisfies `self == other * quotient + remainder`, with the remainder
+    /// having the same sign as `self` (or zero), consistent with `trunc`.
+    ///
+    /// **Panics if `other` is zero.**
+    #[inline]
+    pub fn div_rem(&self, other: &Ratio<T>) -> (T, Ratio<T>) {
+        let quotient = (self / other).to_integer();
+        let remainder = self - other * Ratio::from_integer(quotient.clone());
+        (quotient, remainder)
+    }
+
+    /// Returns the integer quotient of `self / other` rounded towards minus
+    /// infinity, together with the rational remainder.
+    ///
+    /// Satisfies `self == other * quotient + remainder`, with the remainder
+    /// having the same sign as `other` (or zero), consistent with `floor`.
+    ///
+    /// **Panics if `other` is zero.**
+    #[inline]
+    pub fn div_mod_floor(&self, other: &Ratio<T>) -> (T, Ratio<T>) {
+        let quotient = (self / other).floor().to_integer();
+        let remainder = self - other * Ratio::from_integer(quotient.clone());
+        (quotient, remainder)
+    }
+
+    /// Renders the ratio as an exact decimal string, detecting repeating
+    /// cycles by long division.
+    ///
+    /// The repeating part is wrapped in parentheses, so `1/3` renders as
+    /// `"0.(3)"` and `1/7` as `"0.(142857)"`; terminating decimals like
+    /// `1/8` render plainly as `"0.125"`. If neither the expansion
+    /// terminates nor a cycle is found within `max_digits` fractional
+    /// digits, the output is truncated there with a trailing `…`.
+    ///
+    /// For fixed-size `T` this can overflow (and panic in debug builds)
+    /// when the denominator is within a factor of ten of `T::MAX`, since
+    /// the long division multiplies remainders by ten.
+    ///
+    /// **Panics if `denom` is zero.**
+    #[cfg(feature = "std")]
+    pub fn to_decimal_string(&self, max_digits: usize) -> String
+    where
+        T: ToPrimitive,
+    {
+        let zero = T::zero();
+        let mut ten = T::one();
+        for _ in 0..9 {
+            ten = ten + T::one();
+        }
+
+        let negative = (self.numer < zero) != (self.denom < zero) && !self.numer.is_zero();
+        let abs = |x: &T| {
+            if *x < zero {
+                T::zero() - x.clone()
+            } else {
+                x.clone()
+            }
+        };
+        let numer = abs(&self.numer);
+        let denom = abs(&self.denom);
+
+        let (mut int_part, mut rem) = numer.div_rem(&denom);
+
+        // Integer digits, least significant first
+        let mut int_digits = vec![];
+        loop {
+            let (quotient, digit) = int_part.div_rem(&ten);
+            int_digits.push(b'0' + digit.to_u8().unwrap());
+            int_part = quotient;
+            if int_part.is_zero() {
+                break;
+            }
+        }
+
+        let mut out = String::new();
+        if negative {
+            out.push('-');
+        }
+        out.extend(int_digits.iter().rev().map(|&b| b as char));
+        if rem.is_zero() || max_digits == 0 {
+            if !rem.is_zero() {
+                out.push_str(".…");
+            }
+            return out;
+        }
+        out.push('.');
+
+        // Fractional digits; `seen[i]` is the remainder that produced
+        // `digits[i]`, so a repeated remainder means the digits from its
+        // first occurrence onwards form the cycle.
+        let mut seen = vec![];
+        let mut digits = String::new();
+        while !rem.is_zero() && digits.len() < max_digits {
+            if let Some(cycle_start) = seen.iter().position(|r| *r == rem) {
+                out.push_str(&digits[..cycle_start]);
+                out.push('(');
+                out.push_str(&digits[cycle_start..]);
+                out.push(')');
+                return out;
+            }
+            seen.push(rem.clone());
+            let (digit, next_rem) = (rem * ten.clone()).div_rem(&denom);
+            digits.push((b'0' + digit.to_u8().unwrap()) as char);
+            rem = next_rem;
+        }
+        out.push_str(&digits);
+        if !rem.is_zero() {
+            out.push('…');
+        }
+        out
+    }
+}
+
+/// Rounding strategies for decimal conversions that must drop excess
+/// precision, such as [`Ratio::to_decimal_string_rounded`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round towards zero.
+    Truncate,
+    /// Round towards minus infinity.
+    Floor,
+    /// Round towards plus infinity.
+    Ceil,
+    /// Round to nearest; ties away from zero.
+    HalfUp,
+    /// Round to nearest; ties to the even digit.
+    HalfEven,
+}
+
+#[cfg(feature = "num-bigint")]
+impl Ratio<BigInt> {
+    /// Parses a decimal or scientific-notation string into an exact
+    /// rational, e.g. `"123.456e-2"` as `123456/100000`.
+    ///
+    /// Unlike `from_float`, the result is exactly the written decimal:
+    /// `from_decimal_str("0.1")` is precisely `1/10`.
+    pub fn from_decimal_str(s: &str) -> Result<BigRational, ParseRatioError> {
+        fn parse_error() -> ParseRatioError {
+            ParseRatioError {
+                kind: RatioErrorKind::ParseError,
+            }
+        }
+
+        let (mantissa, exponent) = match s.find(|c| c == 'e' || c == 'E') {
+            Some(position) => {
+                let exponent: i32 = s[position + 1..].parse().map_err(|_| parse_error())?;
+                (&s[..position], exponent)
+            }
+            None => (s, 0),
+        };
+        let (negative, unsigned) = match mantissa.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
         };
+        let (int_part, frac_part) = match unsigned.find('.') {
+            Some(position) => (&unsigned[..position], &unsigned[position + 1..]),
+            None => (unsigned, ""),
+        };
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err(parse_error());
+        }
 
-        if half_or_larger {
-            let one: Ratio<T> = One::one();
-            if *self >= Zero::zero() {
-                self.trunc() + one
-            } else {
-                self.trunc() - one
-            }
-        } else {
-            self.trunc()
+        let mut digits = String::with_capacity(int_part.len() + frac_part.len());
+        digits.push_str(int_part);
+        digits.push_str(frac_part);
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(parse_error());
+        }
+        let mut numer: BigInt = digits.parse().map_err(|_| parse_error())?;
+        if negative {
+            numer = -numer;
         }
-    }
 
-    /// Rounds towards zero.
-    #[inline]
-    pub fn trunc(&self) -> Ratio<T> {
-        Ratio::from_integer(self.numer.clone() / self.denom.clone())
+        let ten = BigInt::from_u8(10).unwrap();
+        let mut denom: BigInt = Pow::pow(&ten, frac_part.len());
+        if exponent >= 0 {
+            numer *= Pow::pow(&ten, exponent as usize);
+        } else {
+            denom *= Pow::pow(&ten, (-(exponent as i64)) as usize);
+        }
+        Ok(Ratio::new(numer, denom))
     }
 
-    /// Returns the fractional part of a number, with division rounded towards zero.
+    /// Renders the ratio as a decimal string with at most `max_frac_digits`
+    /// fractional digits, rounding the last digit per `round`.
     ///
-    /// Satisfies `self == self.trunc() + self.fract()`.
-    #[inline]
-    pub fn fract(&self) -> Ratio<T> {
-        Ratio::new_raw(self.numer.clone() % self.denom.clone(), self.denom.clone())
-    }
+    /// When the exact expansion terminates within the limit the output is
+    /// exact, with trailing zeros trimmed (`1/8` gives `"0.125"` for any
+    /// limit of three or more). Otherwise exactly `max_frac_digits` digits
+    /// are printed, with rounding carrying across the decimal point when
+    /// needed (`0.99999` half-up to four digits gives `"1.0000"`).
+    pub fn to_decimal_string_rounded(
+        &self,
+        max_frac_digits: usize,
+        round: RoundingMode,
+    ) -> String {
+        let negative = self.is_negative();
+        let numer = self.numer.abs();
+        let denom = self.denom.abs();
+        let ten = BigInt::from_u8(10).unwrap();
+
+        let scaled = numer * Pow::pow(&ten, max_frac_digits);
+        let (mut quotient, remainder) = scaled.div_rem(&denom);
+        let round_up = if remainder.is_zero() {
+            false
+        } else {
+            let twice = &remainder + &remainder;
+            match round {
+                RoundingMode::Truncate => false,
+                RoundingMode::Floor => negative,
+                RoundingMode::Ceil => !negative,
+                RoundingMode::HalfUp => twice >= denom,
+                RoundingMode::HalfEven => match twice.cmp(&denom) {
+                    cmp::Ordering::Greater => true,
+                    cmp::Ordering::Less => false,
+                    cmp::Ordering::Equal => quotient.is_odd(),
+                },
+            }
+        };
+        if round_up {
+            quotient += 1;
+        }
 
-    /// Raises the `Ratio` to the power of an exponent.
-    #[inline]
-    pub fn pow(&self, expon: i32) -> Ratio<T>
-    where
-        for<'a> &'a T: Pow<u32, Output = T>,
-    {
-        Pow::pow(self, expon)
+        let mut digits = quotient.to_str_radix(10);
+        while digits.len() <= max_frac_digits {
+            digits.insert(0, '0');
+        }
+        let split = digits.len() - max_frac_digits;
+        let mut out = String::new();
+        if negative && digits.bytes().any(|b| b != b'0') {
+            out.push('-');
+        }
+        out.push_str(&digits[..split]);
+        let mut frac = &digits[split..];
+        if remainder.is_zero() {
+            frac = frac.trim_end_matches('0');
+        }
+        if !frac.is_empty() {
+            out.push('.');
+            out.push_str(frac);
+        }
+        out
     }
-}
 
-#[cfg(feature = "num-bigint")]
-impl Ratio<BigInt> {
     /// Converts a float into a rational number.
     pub fn from_float<T: FloatCore>(f: T) -> Option<BigRational> {
         if !f.is_finite() {
@@ -311,6 +1219,121 @@ where
     }
 }
 
+// Conversions between integer widths. Widening is lossless and narrowing
+// checks both components, so neither needs to re-reduce: a ratio in lowest
+// terms with a positive denominator stays that way in the other width.
+
+macro_rules! ratio_widen_impl {
+    ($($src:ty => $($dst:ty),+);+ $(;)?) => {$($(
+        impl From<Ratio<$src>> for Ratio<$dst> {
+            #[inline]
+            fn from(r: Ratio<$src>) -> Ratio<$dst> {
+                Ratio::new_raw(r.numer.into(), r.denom.into())
+            }
+        }
+    )+)+};
+}
+
+ratio_widen_impl! {
+    i8 => i16, i32, i64, i128;
+    i16 => i32, i64, i128;
+    i32 => i64, i128;
+    i64 => i128;
+    u8 => u16, u32, u64, u128;
+    u16 => u32, u64, u128;
+    u32 => u64, u128;
+    u64 => u128;
+}
+
+#[cfg(feature = "num-bigint")]
+macro_rules! ratio_widen_bigint_impl {
+    ($($src:ty),+) => {$(
+        impl From<Ratio<$src>> for Ratio<BigInt> {
+            #[inline]
+            fn from(r: Ratio<$src>) -> Ratio<BigInt> {
+                Ratio::new_raw(r.numer.into(), r.denom.into())
+            }
+        }
+    )+};
+}
+
+#[cfg(feature = "num-bigint")]
+ratio_widen_bigint_impl!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Error returned by the narrowing `TryFrom` conversions between `Ratio`
+/// integer widths, when the numerator or denominator does not fit.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TryFromRatioError {
+    _priv: (),
+}
+
+impl fmt::Display for TryFromRatioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "numerator or denominator out of range".fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TryFromRatioError {}
+
+macro_rules! ratio_narrow_impl {
+    ($($src:ty => $($dst:ty),+);+ $(;)?) => {$($(
+        impl TryFrom<Ratio<$src>> for Ratio<$dst> {
+            type Error = TryFromRatioError;
+            #[inline]
+            fn try_from(r: Ratio<$src>) -> Result<Ratio<$dst>, TryFromRatioError> {
+                match (<$dst>::try_from(r.numer), <$dst>::try_from(r.denom)) {
+                    (Ok(numer), Ok(denom)) => Ok(Ratio::new_raw(numer, denom)),
+                    _ => Err(TryFromRatioError { _priv: () }),
+                }
+            }
+        }
+    )+)+};
+}
+
+ratio_narrow_impl! {
+    i128 => i64, i32, i16, i8;
+    i64 => i32, i16, i8;
+    i32 => i16, i8;
+    i16 => i8;
+    u128 => u64, u32, u16, u8;
+    u64 => u32, u16, u8;
+    u32 => u16, u8;
+    u16 => u8;
+}
+
+#[cfg(feature = "num-bigint")]
+macro_rules! ratio_narrow_bigint_impl {
+    ($($dst:ty => $method:ident;)+) => {$(
+        impl TryFrom<Ratio<BigInt>> for Ratio<$dst> {
+            type Error = TryFromRatioError;
+            #[inline]
+            fn try_from(r: Ratio<BigInt>) -> Result<Ratio<$dst>, TryFromRatioError> {
+                match (r.numer.$method(), r.denom.$method()) {
+                    (Some(numer), Some(denom)) => Ok(Ratio::new_raw(numer, denom)),
+                    _ => Err(TryFromRatioError { _priv: () }),
+                }
+            }
+        }
+    )+};
+}
+
+#[cfg(feature = "num-bigint")]
+ratio_narrow_bigint_impl! {
+    i8 => to_i8;
+    i16 => to_i16;
+    i32 => to_i32;
+    i64 => to_i64;
+    i128 => to_i128;
+    isize => to_isize;
+    u8 => to_u8;
+    u16 => to_u16;
+    u32 => to_u32;
+    u64 => to_u64;
+    u128 => to_u128;
+    usize => to_usize;
+}
+
 // Comparisons
 
 // Mathematically, comparing a/b and c/d is the same as comparing a*d and b*c, but it's very easy
@@ -334,6 +1357,22 @@ impl<T: Clone + Integer> Ord for Ratio<T> {
             if self.numer.is_zero() {
                 return cmp::Ordering::Equal;
             }
+            let self_neg_denom = self.denom < T::zero();
+            let other_neg_denom = other.denom < T::zero();
+            if self_neg_denom != other_neg_denom {
+                // Denominators of opposite signs mean the values have opposite
+                // signs too, so inverse comparison would give the wrong answer.
+                let ord = if self_neg_denom {
+                    cmp::Ordering::Less
+                } else {
+                    cmp::Ordering::Greater
+                };
+                return if self.numer < T::zero() {
+                    ord.reverse()
+                } else {
+                    ord
+                };
+            }
             let ord = self.denom.cmp(&other.denom);
             return if self.numer < T::zero() {
                 ord
@@ -385,6 +1424,46 @@ impl<T: Clone + Integer> PartialEq for Ratio<T> {
 
 impl<T: Clone + Integer> Eq for Ratio<T> {}
 
+// Comparisons against the bare integer type, so `ratio < 1` works without
+// wrapping the scalar in `Ratio::from_integer`. These go through `cmp`,
+// which is overflow-safe, rather than multiplying `denom * rhs` directly.
+impl<T: Clone + Integer> PartialEq<T> for Ratio<T> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.cmp(&Ratio::from_integer(other.clone())) == cmp::Ordering::Equal
+    }
+}
+
+impl<T: Clone + Integer> PartialOrd<T> for Ratio<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &T) -> Option<cmp::Ordering> {
+        Some(self.cmp(&Ratio::from_integer(other.clone())))
+    }
+}
+
+// The mirrored impls can't be written for a generic `T` (coherence), so
+// cover the primitive integers.
+macro_rules! scalar_cmp_impl {
+    ($($t:ty)*) => {$(
+        impl PartialEq<Ratio<$t>> for $t {
+            #[inline]
+            fn eq(&self, other: &Ratio<$t>) -> bool {
+                other == self
+            }
+        }
+
+        impl PartialOrd<Ratio<$t>> for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &Ratio<$t>) -> Option<cmp::Ordering> {
+                other.partial_cmp(self).map(cmp::Ordering::reverse)
+            }
+        }
+    )*};
+}
+
+scalar_cmp_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+
 // NB: We can't just `#[derive(Hash)]`, because it needs to agree
 // with `Eq` even for non-reduced ratios.
 impl<T: Clone + Integer + Hash> Hash for Ratio<T> {
@@ -409,12 +1488,73 @@ mod iter_sum_product {
     use num_integer::Integer;
     use num_traits::{One, Zero};
 
+    // A left-to-right fold recomputes ever-growing lcm/gcd values at every
+    // step, which is quadratic in gcd work when the denominators are pairwise
+    // coprime. Combining the elements as a balanced binary tree instead keeps
+    // the intermediate denominators as small as possible, while producing
+    // exactly the same value since rational arithmetic is exact.
+
+    /// Combines up to `2^depth` leading elements of `iter` into one balanced
+    /// partial result, or returns `None` if the iterator is exhausted.
+    ///
+    /// An `absorbing` partial (e.g. zero for products) is returned as-is
+    /// instead of combining further elements into it, matching the cheap
+    /// short-circuit a plain fold gets for free.
+    fn balanced_partial<T, I, F>(
+        iter: &mut I,
+        f: &mut F,
+        absorbing: fn(&Ratio<T>) -> bool,
+        depth: u32,
+    ) -> Option<Ratio<T>>
+    where
+        T: Integer + Clone,
+        I: Iterator<Item = Ratio<T>>,
+        F: FnMut(Ratio<T>, Ratio<T>) -> Ratio<T>,
+    {
+        if depth == 0 {
+            return iter.next();
+        }
+        let mut acc = balanced_partial(iter, f, absorbing, depth - 1)?;
+        if absorbing(&acc) {
+            return Some(acc);
+        }
+        if let Some(rhs) = balanced_partial(iter, f, absorbing, depth - 1) {
+            acc = f(acc, rhs);
+        }
+        Some(acc)
+    }
+
+    /// Reduces `iter` with `f` in a balanced (tree) order without allocating:
+    /// the accumulator is combined with partials of doubling size, so every
+    /// element sits at depth `O(log n)` of the reduction tree.
+    fn tree_reduce<T, I, F>(
+        mut iter: I,
+        mut f: F,
+        absorbing: fn(&Ratio<T>) -> bool,
+    ) -> Option<Ratio<T>>
+    where
+        T: Integer + Clone,
+        I: Iterator<Item = Ratio<T>>,
+        F: FnMut(Ratio<T>, Ratio<T>) -> Ratio<T>,
+    {
+        let mut acc = iter.next()?;
+        let mut depth = 0;
+        while !absorbing(&acc) {
+            match balanced_partial(&mut iter, &mut f, absorbing, depth) {
+                Some(rhs) => acc = f(acc, rhs),
+                None => break,
+            }
+            depth += 1;
+        }
+        Some(acc)
+    }
+
     impl<T: Integer + Clone> Sum for Ratio<T> {
         fn sum<I>(iter: I) -> Self
         where
             I: Iterator<Item = Ratio<T>>,
         {
-            iter.fold(Self::zero(), |sum, num| sum + num)
+            tree_reduce(iter, |sum, num| sum + num, |_| false).unwrap_or_else(Self::zero)
         }
     }
 
@@ -432,7 +1572,7 @@ mod iter_sum_product {
         where
             I: Iterator<Item = Ratio<T>>,
         {
-            iter.fold(Self::one(), |prod, num| prod * num)
+            tree_reduce(iter, |prod, num| prod * num, Ratio::is_zero).unwrap_or_else(Self::one)
         }
     }
 
@@ -464,7 +1604,7 @@ mod opassign {
                 self.numer = lhs_numer + rhs_numer;
                 self.denom = lcm;
             }
-            self.reduce();
+            self.reduce_with("add assignment");
         }
     }
 
@@ -477,7 +1617,7 @@ mod opassign {
             self.numer *= other.denom / gcd_bd.clone();
             self.denom /= gcd_bd;
             self.denom *= other.numer / gcd_ac;
-            self.reduce(); // TODO: remove this line. see #8.
+            self.reduce_with("divide assignment"); // TODO: remove this line. see #8.
         }
     }
 
@@ -490,7 +1630,7 @@ mod opassign {
             self.numer *= other.numer / gcd_bc.clone();
             self.denom /= gcd_bc;
             self.denom *= other.denom / gcd_ad;
-            self.reduce(); // TODO: remove this line. see #8.
+            self.reduce_with("multiply assignment"); // TODO: remove this line. see #8.
         }
     }
 
@@ -505,7 +1645,7 @@ mod opassign {
                 self.numer = lhs_numer % rhs_numer;
                 self.denom = lcm;
             }
-            self.reduce();
+            self.reduce_with("remainder assignment");
         }
     }
 
@@ -520,7 +1660,7 @@ mod opassign {
                 self.numer = lhs_numer - rhs_numer;
                 self.denom = lcm;
             }
-            self.reduce();
+            self.reduce_with("subtract assignment");
         }
     }
 
@@ -528,7 +1668,7 @@ mod opassign {
     impl<T: Clone + Integer + NumAssign> AddAssign<T> for Ratio<T> {
         fn add_assign(&mut self, other: T) {
             self.numer += self.denom.clone() * other;
-            self.reduce();
+            self.reduce_with("add assignment");
         }
     }
 
@@ -537,7 +1677,7 @@ mod opassign {
             let gcd = self.numer.gcd(&other);
             self.numer /= gcd.clone();
             self.denom *= other / gcd;
-            self.reduce(); // TODO: remove this line. see #8.
+            self.reduce_with("divide assignment"); // TODO: remove this line. see #8.
         }
     }
 
@@ -546,7 +1686,7 @@ mod opassign {
             let gcd = self.denom.gcd(&other);
             self.denom /= gcd.clone();
             self.numer *= other / gcd;
-            self.reduce(); // TODO: remove this line. see #8.
+            self.reduce_with("multiply assignment"); // TODO: remove this line. see #8.
         }
     }
 
@@ -554,7 +1694,7 @@ mod opassign {
     impl<T: Clone + Integer + NumAssign> RemAssign<T> for Ratio<T> {
         fn rem_assign(&mut self, other: T) {
             self.numer %= self.denom.clone() * other;
-            self.reduce();
+            self.reduce_with("remainder assignment");
         }
     }
 
@@ -562,7 +1702,7 @@ mod opassign {
     impl<T: Clone + Integer + NumAssign> SubAssign<T> for Ratio<T> {
         fn sub_assign(&mut self, other: T) {
             self.numer -= self.denom.clone() * other;
-            self.reduce();
+            self.reduce_with("subtract assignment");
         }
     }
 
@@ -685,9 +1825,10 @@ where
     fn mul(self, rhs: Ratio<T>) -> Ratio<T> {
         let gcd_ad = self.numer.gcd(&rhs.denom);
         let gcd_bc = self.denom.gcd(&rhs.numer);
-        Ratio::new(
+        Ratio::new_op(
             self.numer / gcd_ad.clone() * (rhs.numer / gcd_bc.clone()),
             self.denom / gcd_bc * (rhs.denom / gcd_ad),
+            "multiplication",
         )
     }
 }
@@ -700,7 +1841,11 @@ where
     #[inline]
     fn mul(self, rhs: T) -> Ratio<T> {
         let gcd = self.denom.gcd(&rhs);
-        Ratio::new(self.numer * (rhs / gcd.clone()), self.denom / gcd)
+        Ratio::new_op(
+            self.numer * (rhs / gcd.clone()),
+            self.denom / gcd,
+            "multiplication",
+        )
     }
 }
 
@@ -716,9 +1861,10 @@ where
     fn div(self, rhs: Ratio<T>) -> Ratio<T> {
         let gcd_ac = self.numer.gcd(&rhs.numer);
         let gcd_bd = self.denom.gcd(&rhs.denom);
-        Ratio::new(
+        Ratio::new_op(
             self.numer / gcd_ac.clone() * (rhs.denom / gcd_bd.clone()),
             self.denom / gcd_bd * (rhs.numer / gcd_ac),
+            "division",
         )
     }
 }
@@ -732,25 +1878,33 @@ where
     #[inline]
     fn div(self, rhs: T) -> Ratio<T> {
         let gcd = self.numer.gcd(&rhs);
-        Ratio::new(self.numer / gcd.clone(), self.denom * (rhs / gcd))
+        Ratio::new_op(
+            self.numer / gcd.clone(),
+            self.denom * (rhs / gcd),
+            "division",
+        )
     }
 }
 
 macro_rules! arith_impl {
-    (impl $imp:ident, $method:ident) => {
+    (impl $imp:ident, $method:ident, $op:expr) => {
         forward_all_binop!(impl $imp, $method);
         // Abstracts a/b `op` c/d = (a*lcm/b `op` c*lcm/d)/lcm where lcm = lcm(b,d)
         impl<T: Clone + Integer> $imp<Ratio<T>> for Ratio<T> {
             type Output = Ratio<T>;
             #[inline]
             fn $method(self, rhs: Ratio<T>) -> Ratio<T> {
+                // catch zero denominators before the lcm scaling divides by them
+                if self.denom.is_zero() || rhs.denom.is_zero() {
+                    denom_zero_panic($op);
+                }
                 if self.denom == rhs.denom {
-                    return Ratio::new(self.numer.$method(rhs.numer), rhs.denom);
+                    return Ratio::new_op(self.numer.$method(rhs.numer), rhs.denom, $op);
                 }
                 let lcm = self.denom.lcm(&rhs.denom);
                 let lhs_numer = self.numer * (lcm.clone() / self.denom);
                 let rhs_numer = rhs.numer * (lcm.clone() / rhs.denom);
-                Ratio::new(lhs_numer.$method(rhs_numer), lcm)
+                Ratio::new_op(lhs_numer.$method(rhs_numer), lcm, $op)
             }
         }
         // Abstracts the a/b `op` c/1 = (a*1 `op` b*c) / (b*1) = (a `op` b*c) / b pattern
@@ -758,15 +1912,71 @@ macro_rules! arith_impl {
             type Output = Ratio<T>;
             #[inline]
             fn $method(self, rhs: T) -> Ratio<T> {
-                Ratio::new(self.numer.$method(self.denom.clone() * rhs), self.denom)
+                Ratio::new_op(
+                    self.numer.$method(self.denom.clone() * rhs),
+                    self.denom,
+                    $op,
+                )
+            }
+        }
+    };
+}
+
+arith_impl!(impl Add, add, "addition");
+arith_impl!(impl Sub, sub, "subtraction");
+arith_impl!(impl Rem, rem, "remainder");
+
+// The mirrored `$t op Ratio<$t>` impls can't be written as blanket impls
+// over `T` for coherence reasons, so enumerate the primitive integer types.
+macro_rules! mirrored_binop_for {
+    (impl $imp:ident, $method:ident for $($t:ty)*) => {$(
+        impl $imp<Ratio<$t>> for $t {
+            type Output = Ratio<$t>;
+
+            #[inline]
+            fn $method(self, rhs: Ratio<$t>) -> Ratio<$t> {
+                Ratio::from_integer(self).$method(rhs)
+            }
+        }
+        impl<'b> $imp<&'b Ratio<$t>> for $t {
+            type Output = Ratio<$t>;
+
+            #[inline]
+            fn $method(self, rhs: &'b Ratio<$t>) -> Ratio<$t> {
+                Ratio::from_integer(self).$method(rhs)
+            }
+        }
+        impl<'a> $imp<Ratio<$t>> for &'a $t {
+            type Output = Ratio<$t>;
+
+            #[inline]
+            fn $method(self, rhs: Ratio<$t>) -> Ratio<$t> {
+                Ratio::from_integer(*self).$method(rhs)
             }
         }
+        impl<'a, 'b> $imp<&'b Ratio<$t>> for &'a $t {
+            type Output = Ratio<$t>;
+
+            #[inline]
+            fn $method(self, rhs: &'b Ratio<$t>) -> Ratio<$t> {
+                Ratio::from_integer(*self).$method(rhs)
+            }
+        }
+    )*};
+}
+
+macro_rules! mirrored_binop {
+    (impl $imp:ident, $method:ident) => {
+        mirrored_binop_for!(impl $imp, $method for
+            i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
     };
 }
 
-arith_impl!(impl Add, add);
-arith_impl!(impl Sub, sub);
-arith_impl!(impl Rem, rem);
+mirrored_binop!(impl Add, add);
+mirrored_binop!(impl Sub, sub);
+mirrored_binop!(impl Mul, mul);
+mirrored_binop!(impl Div, div);
+mirrored_binop!(impl Rem, rem);
 
 // a/b * c/d = (a*c)/(b*d)
 impl<T> CheckedMul for Ratio<T>
@@ -854,6 +2064,197 @@ checked_arith_impl!(impl CheckedAdd, checked_add);
 // a/b - c/d = (lcm/b*a - lcm/d*c)/lcm, where lcm = lcm(b,d)
 checked_arith_impl!(impl CheckedSub, checked_sub);
 
+// Fused a*b + c. The product is pre-divided by gcd_ad/gcd_bc like `Mul`,
+// which leaves it already in lowest terms, and the sum then goes through
+// the lcm form like `Add` -- so the intermediates stay as small as in the
+// separate operations but only the one final `Ratio::new` reduce happens,
+// instead of one per operator.
+impl<T: Clone + Integer> MulAdd for Ratio<T> {
+    type Output = Ratio<T>;
+
+    fn mul_add(self, b: Ratio<T>, c: Ratio<T>) -> Ratio<T> {
+        let gcd_ad = self.numer.gcd(&b.denom);
+        let gcd_bc = self.denom.gcd(&b.numer);
+        let prod_numer = (self.numer / gcd_ad.clone()) * (b.numer / gcd_bc.clone());
+        let prod_denom = (self.denom / gcd_bc) * (b.denom / gcd_ad);
+        let gcd = prod_denom.gcd(&c.denom);
+        let lcm = (prod_denom.clone() / gcd) * c.denom.clone();
+        let lhs_numer = (lcm.clone() / prod_denom) * prod_numer;
+        let rhs_numer = (lcm.clone() / c.denom) * c.numer;
+        Ratio::new(lhs_numer + rhs_numer, lcm)
+    }
+}
+
+impl<T: Clone + Integer> MulAddAssign for Ratio<T> {
+    #[inline]
+    fn mul_add_assign(&mut self, b: Ratio<T>, c: Ratio<T>) {
+        *self = self.clone().mul_add(b, c);
+    }
+}
+
+// In-place counterparts of the checked operators: the result is computed
+// into a temporary and only written back on success.
+impl<T: Clone + Integer + CheckedMul> Ratio<T> {
+    /// Fused multiply-add `self * b + c`, returning `None` on overflow.
+    ///
+    /// The checked counterpart of the `MulAdd` implementation, with the
+    /// same gcd extraction before each multiplication, so it succeeds
+    /// whenever `checked_mul` followed by `checked_add` would — including
+    /// cases where the textbook `(a.n*b.n*c.d + c.n*a.d*b.d)` intermediates
+    /// overflow long before the reduced result does.
+    pub fn checked_mul_add(&self, b: &Ratio<T>, c: &Ratio<T>) -> Option<Ratio<T>>
+    where
+        T: CheckedAdd,
+    {
+        let gcd_ad = self.numer.gcd(&b.denom);
+        let gcd_bc = self.denom.gcd(&b.numer);
+        let prod_numer = (self.numer.clone() / gcd_ad.clone())
+            .checked_mul(&(b.numer.clone() / gcd_bc.clone()))?;
+        let prod_denom =
+            (self.denom.clone() / gcd_bc).checked_mul(&(b.denom.clone() / gcd_ad))?;
+        let gcd = prod_denom.gcd(&c.denom);
+        let lcm = (prod_denom.clone() / gcd).checked_mul(&c.denom)?;
+        let lhs_numer = (lcm.clone() / prod_denom).checked_mul(&prod_numer)?;
+        let rhs_numer = (lcm.clone() / c.denom.clone()).checked_mul(&c.numer)?;
+        Some(Ratio::new(lhs_numer.checked_add(&rhs_numer)?, lcm))
+    }
+
+    /// Adds `rhs` to `self` in place, returning whether the addition
+    /// succeeded. On overflow `self` is left unchanged.
+    #[inline]
+    pub fn checked_add_assign(&mut self, rhs: &Ratio<T>) -> bool
+    where
+        T: CheckedAdd,
+    {
+        match self.checked_add(rhs) {
+            Some(sum) => {
+                *self = sum;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Subtracts `rhs` from `self` in place, returning whether the
+    /// subtraction succeeded. On overflow `self` is left unchanged.
+    #[inline]
+    pub fn checked_sub_assign(&mut self, rhs: &Ratio<T>) -> bool
+    where
+        T: CheckedSub,
+    {
+        match self.checked_sub(rhs) {
+            Some(difference) => {
+                *self = difference;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Multiplies `self` by `rhs` in place, returning whether the
+    /// multiplication succeeded. On overflow `self` is left unchanged.
+    #[inline]
+    pub fn checked_mul_assign(&mut self, rhs: &Ratio<T>) -> bool {
+        match self.checked_mul(rhs) {
+            Some(product) => {
+                *self = product;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Divides `self` by `rhs` in place, returning whether the division
+    /// succeeded. On overflow or division by zero `self` is left unchanged.
+    #[inline]
+    pub fn checked_div_assign(&mut self, rhs: &Ratio<T>) -> bool {
+        match self.checked_div(rhs) {
+            Some(quotient) => {
+                *self = quotient;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+// Saturating counterparts of the checked operators, for code that can
+// neither panic nor thread `Option`s through every expression. Overflow
+// clamps to `T::max_value()` or `T::min_value()` over one, based on the
+// mathematical sign of the exact result, which can always be determined
+// without overflowing from the operand signs and a `cmp` (which is
+// division-based, not multiplication-based). Note that the checked
+// operators also fail when only the intermediate common denominator
+// overflows, so a result that would itself have been representable can
+// still saturate.
+impl<T> Ratio<T>
+where
+    T: Clone + Integer + Bounded + CheckedMul,
+{
+    /// Adds two ratios, saturating at the bounds instead of panicking.
+    pub fn saturating_add(&self, rhs: &Ratio<T>) -> Ratio<T>
+    where
+        T: CheckedAdd,
+    {
+        match self.checked_add(rhs) {
+            Some(sum) => sum,
+            None => match (self.numer < T::zero(), rhs.numer < T::zero()) {
+                (false, false) => Ratio::from_integer(T::max_value()),
+                (true, true) => Ratio::from_integer(T::min_value()),
+                // With mixed signs the sum takes the sign of the larger
+                // magnitude, i.e. of `self` compared against `-rhs`.
+                // Negating only the (positive) denominator sidesteps a
+                // possible `T::MIN` numerator; `cmp` handles the
+                // unreduced negative denominator.
+                _ => {
+                    let neg_rhs =
+                        Ratio::new_raw(rhs.numer.clone(), T::zero() - rhs.denom.clone());
+                    match self.cmp(&neg_rhs) {
+                        cmp::Ordering::Greater => Ratio::from_integer(T::max_value()),
+                        cmp::Ordering::Less => Ratio::from_integer(T::min_value()),
+                        cmp::Ordering::Equal => Ratio::zero(),
+                    }
+                }
+            },
+        }
+    }
+
+    /// Subtracts two ratios, saturating at the bounds instead of panicking.
+    ///
+    /// For unsigned `T` this clamps a negative result to zero.
+    pub fn saturating_sub(&self, rhs: &Ratio<T>) -> Ratio<T>
+    where
+        T: CheckedSub,
+    {
+        match self.checked_sub(rhs) {
+            Some(difference) => difference,
+            // The difference has the sign of `self.cmp(rhs)`
+            None => match self.cmp(rhs) {
+                cmp::Ordering::Greater => Ratio::from_integer(T::max_value()),
+                cmp::Ordering::Less => Ratio::from_integer(T::min_value()),
+                cmp::Ordering::Equal => Ratio::zero(),
+            },
+        }
+    }
+
+    /// Multiplies two ratios, saturating at the bounds instead of
+    /// panicking.
+    pub fn saturating_mul(&self, rhs: &Ratio<T>) -> Ratio<T> {
+        match self.checked_mul(rhs) {
+            Some(product) => product,
+            None => {
+                if self.is_zero() || rhs.is_zero() {
+                    Ratio::zero()
+                } else if (self.numer < T::zero()) == (rhs.numer < T::zero()) {
+                    Ratio::from_integer(T::max_value())
+                } else {
+                    Ratio::from_integer(T::min_value())
+                }
+            }
+        }
+    }
+}
+
 impl<T> Neg for Ratio<T>
 where
     T: Clone + Integer + Neg<Output = T>,
@@ -967,6 +2368,23 @@ impl<T: Clone + Integer> Num for Ratio<T> {
     }
 }
 
+/// The bounds are the extreme representable *integers*
+/// `T::min_value()/1` and `T::max_value()/1`. They are not the extremes
+/// of every expressible ratio — e.g. `1/T::max_value()` is a smaller
+/// positive value than `1`, and there is no smallest one — but every
+/// representable ratio compares within `min_value()..=max_value()`.
+impl<T: Clone + Integer + Bounded> Bounded for Ratio<T> {
+    #[inline]
+    fn min_value() -> Ratio<T> {
+        Ratio::new_raw(T::min_value(), T::one())
+    }
+
+    #[inline]
+    fn max_value() -> Ratio<T> {
+        Ratio::new_raw(T::max_value(), T::one())
+    }
+}
+
 impl<T: Clone + Integer + Signed> Signed for Ratio<T> {
     #[inline]
     fn abs(&self) -> Ratio<T> {
@@ -1010,7 +2428,77 @@ impl<T: Clone + Integer + Signed> Signed for Ratio<T> {
     }
 }
 
-// String conversions
+impl<T: Clone + Integer + Signed> Euclid for Ratio<T> {
+    /// Returns the Euclidean quotient, i.e. `(self / v)` rounded so that
+    /// `self.rem_euclid(v)` is non-negative.
+    #[inline]
+    fn div_euclid(&self, v: &Ratio<T>) -> Ratio<T> {
+        let q = self / v;
+        if v.is_negative() {
+            q.ceil()
+        } else {
+            q.floor()
+        }
+    }
+
+    /// Returns the Euclidean remainder, which always satisfies
+    /// `0 <= self.rem_euclid(v) < v.abs()`.
+    #[inline]
+    fn rem_euclid(&self, v: &Ratio<T>) -> Ratio<T> {
+        self - v * self.div_euclid(v)
+    }
+}
+
+// String conversions
+// Fixed-size stack buffer so the no_std path can pre-render the ratio and
+// then go through `pad_integral`, exactly like the std path does.
+#[cfg(not(feature = "std"))]
+struct StackString {
+    cursor: usize,
+    buf: [u8; 128],
+}
+
+#[cfg(not(feature = "std"))]
+impl StackString {
+    fn new() -> StackString {
+        StackString {
+            cursor: 0,
+            buf: [0; 128],
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.cursor]).unwrap_or("")
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl fmt::Write for StackString {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.cursor + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.cursor..end].copy_from_slice(s.as_bytes());
+        self.cursor = end;
+        Ok(())
+    }
+}
+
+// Shared tail of both formatting paths: strip a leading minus sign and let
+// `pad_integral` apply sign, alternate prefix, fill and width uniformly.
+fn pad_ratio_str(f: &mut Formatter<'_>, prefix: &str, pre_pad: &str) -> fmt::Result {
+    // TODO: replace with strip_prefix, when stabalized
+    let (pre_pad, non_negative) = {
+        if pre_pad.starts_with("-") {
+            (&pre_pad[1..], false)
+        } else {
+            (&pre_pad[..], true)
+        }
+    };
+    f.pad_integral(non_negative, prefix, pre_pad)
+}
+
 macro_rules! impl_formatting {
     ($fmt_trait:ident, $prefix:expr, $fmt_str:expr, $fmt_alt:expr) => {
         impl<T: $fmt_trait + Clone + Integer> $fmt_trait for Ratio<T> {
@@ -1025,50 +2513,81 @@ macro_rules! impl_formatting {
                         format!(concat!($fmt_str, "/", $fmt_str), self.numer, self.denom)
                     }
                 };
-                // TODO: replace with strip_prefix, when stabalized
-                let (pre_pad, non_negative) = {
-                    if pre_pad.starts_with("-") {
-                        (&pre_pad[1..], false)
-                    } else {
-                        (&pre_pad[..], true)
-                    }
-                };
-                f.pad_integral(non_negative, $prefix, pre_pad)
+                pad_ratio_str(f, $prefix, &pre_pad)
             }
             #[cfg(not(feature = "std"))]
             fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-                let plus = if f.sign_plus() && self.numer >= T::zero() {
-                    "+"
-                } else {
-                    ""
-                };
+                use fmt::Write;
+                let mut pre_pad = StackString::new();
                 if self.denom.is_one() {
-                    if f.alternate() {
-                        write!(f, concat!("{}", $fmt_alt), plus, self.numer)
-                    } else {
-                        write!(f, concat!("{}", $fmt_str), plus, self.numer)
-                    }
+                    write!(pre_pad, $fmt_str, self.numer)?;
                 } else {
                     if f.alternate() {
                         write!(
-                            f,
-                            concat!("{}", $fmt_alt, "/", $fmt_alt),
-                            plus, self.numer, self.denom
-                        )
+                            pre_pad,
+                            concat!($fmt_str, "/", $fmt_alt),
+                            self.numer, self.denom
+                        )?;
                     } else {
                         write!(
-                            f,
-                            concat!("{}", $fmt_str, "/", $fmt_str),
-                            plus, self.numer, self.denom
-                        )
+                            pre_pad,
+                            concat!($fmt_str, "/", $fmt_str),
+                            self.numer, self.denom
+                        )?;
                     }
                 }
+                pad_ratio_str(f, $prefix, pre_pad.as_str())
             }
         }
     };
 }
 
-impl_formatting!(Display, "", "{}", "{:#}");
+// Display gets a dedicated impl instead of `impl_formatting!` so the
+// alternate flag can render mixed numbers (`{:#}` of 7/3 is `2 1/3`); the
+// radix formats below keep the usual `0x`-prefix meaning of `#`.
+impl<T: Display + Clone + Integer> Display for Ratio<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let pre_pad = if self.denom.is_one() {
+            format!("{}", self.numer)
+        } else if f.alternate() {
+            let (whole, rem) = self.numer.div_rem(&self.denom);
+            if rem.is_zero() {
+                format!("{}", whole)
+            } else if whole.is_zero() {
+                format!("{}/{}", rem, self.denom)
+            } else {
+                let rem = if rem < T::zero() { T::zero() - rem } else { rem };
+                format!("{} {}/{}", whole, rem, self.denom)
+            }
+        } else {
+            format!("{}/{}", self.numer, self.denom)
+        };
+        pad_ratio_str(f, "", &pre_pad)
+    }
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+        let mut pre_pad = StackString::new();
+        if self.denom.is_one() {
+            write!(pre_pad, "{}", self.numer)?;
+        } else if f.alternate() {
+            let (whole, rem) = self.numer.div_rem(&self.denom);
+            if rem.is_zero() {
+                write!(pre_pad, "{}", whole)?;
+            } else if whole.is_zero() {
+                write!(pre_pad, "{}/{}", rem, self.denom)?;
+            } else {
+                let rem = if rem < T::zero() { T::zero() - rem } else { rem };
+                write!(pre_pad, "{} {}/{}", whole, rem, self.denom)?;
+            }
+        } else {
+            write!(pre_pad, "{}/{}", self.numer, self.denom)?;
+        }
+        pad_ratio_str(f, "", pre_pad.as_str())
+    }
+}
+
 impl_formatting!(Octal, "0o", "{:o}", "{:#o}");
 impl_formatting!(Binary, "0b", "{:b}", "{:#b}");
 impl_formatting!(LowerHex, "0x", "{:x}", "{:#x}");
@@ -1153,10 +2672,12 @@ pub struct ParseRatioError {
     kind: RatioErrorKind,
 }
 
+/// The failure cases of parsing and of [`Ratio::try_reduce`].
 #[derive(Copy, Clone, Debug, PartialEq)]
-enum RatioErrorKind {
+pub enum RatioErrorKind {
     ParseError,
     ZeroDenominator,
+    NegationOverflow,
 }
 
 impl fmt::Display for ParseRatioError {
@@ -1178,6 +2699,7 @@ impl RatioErrorKind {
         match *self {
             RatioErrorKind::ParseError => "failed to parse integer",
             RatioErrorKind::ZeroDenominator => "zero value denominator",
+            RatioErrorKind::NegationOverflow => "overflow normalizing the denominator sign",
         }
     }
 }
@@ -1253,6 +2775,197 @@ from_primitive_integer!(u64, approximate_float_unsigned);
 from_primitive_integer!(u128, approximate_float_unsigned);
 from_primitive_integer!(usize, approximate_float_unsigned);
 
+/// Error returned by the exact `TryFrom` float conversions.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TryFromFloatError {
+    /// The input was NaN or infinite.
+    NotFinite,
+    /// The exact dyadic value does not fit in the target integer type.
+    Overflow,
+}
+
+impl fmt::Display for TryFromFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TryFromFloatError::NotFinite => "float is NaN or infinite".fmt(f),
+            TryFromFloatError::Overflow => "exact value does not fit in the integer type".fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TryFromFloatError {}
+
+/// Exact conversion of a finite float into `mantissa * 2^exponent` form,
+/// failing with `Overflow` instead of approximating. The lossy path stays
+/// available through `FromPrimitive` and `approximate_float`.
+fn try_ratio_from_float<T, F>(f: F) -> Result<Ratio<T>, TryFromFloatError>
+where
+    T: Clone + Integer + FromPrimitive + CheckedMul + CheckedSub,
+    F: FloatCore,
+{
+    if !f.is_finite() {
+        return Err(TryFromFloatError::NotFinite);
+    }
+    if f.is_zero() {
+        return Ok(Ratio::from_integer(T::zero()));
+    }
+    let (mut mantissa, exponent, sign) = f.integer_decode();
+    let mut exponent = exponent as i32;
+    // cancel the common factors of two up front so the denominator is
+    // already in lowest terms
+    while exponent < 0 && mantissa & 1 == 0 {
+        mantissa >>= 1;
+        exponent += 1;
+    }
+    let mut numer = T::from_u64(mantissa).ok_or(TryFromFloatError::Overflow)?;
+    let two = T::one() + T::one();
+    let mut denom = T::one();
+    if exponent >= 0 {
+        for _ in 0..exponent {
+            numer = numer.checked_mul(&two).ok_or(TryFromFloatError::Overflow)?;
+        }
+    } else {
+        for _ in 0..-exponent {
+            denom = denom.checked_mul(&two).ok_or(TryFromFloatError::Overflow)?;
+        }
+    }
+    if sign < 0 {
+        numer = T::zero()
+            .checked_sub(&numer)
+            .ok_or(TryFromFloatError::Overflow)?;
+    }
+    Ok(Ratio::new_raw(numer, denom))
+}
+
+macro_rules! try_from_float_impl {
+    ($($typ:ty)*) => {$(
+        impl TryFrom<f32> for Ratio<$typ> {
+            type Error = TryFromFloatError;
+            fn try_from(f: f32) -> Result<Self, Self::Error> {
+                try_ratio_from_float(f)
+            }
+        }
+
+        impl TryFrom<f64> for Ratio<$typ> {
+            type Error = TryFromFloatError;
+            fn try_from(f: f64) -> Result<Self, Self::Error> {
+                try_ratio_from_float(f)
+            }
+        }
+    )*};
+}
+
+try_from_float_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+/// Maps an integer type to the next larger one, for the `*_widening`
+/// arithmetic on `Ratio`.
+///
+/// `i128`, `u128` and the pointer-sized types have no widened target and
+/// therefore no impl; use `BigRational` when those can overflow.
+pub trait WidenInt {
+    /// The integer type with twice the width of `Self`.
+    type Wide: Clone + Integer;
+
+    /// Converts into the widened type. This is always lossless.
+    fn widen(self) -> Self::Wide;
+}
+
+macro_rules! widen_int_impl {
+    ($($narrow:ty => $wide:ty,)*) => {$(
+        impl WidenInt for $narrow {
+            type Wide = $wide;
+
+            #[inline]
+            fn widen(self) -> $wide {
+                self as $wide
+            }
+        }
+    )*};
+}
+
+widen_int_impl!(
+    i8 => i16,
+    i16 => i32,
+    i32 => i64,
+    i64 => i128,
+    u8 => u16,
+    u16 => u32,
+    u32 => u64,
+    u64 => u128,
+);
+
+impl<T: Clone + Integer + WidenInt> Ratio<T> {
+    /// Returns self with both components converted to the widened type.
+    #[inline]
+    fn widened(&self) -> Ratio<T::Wide> {
+        Ratio::new_raw(self.numer.clone().widen(), self.denom.clone().widen())
+    }
+
+    /// Adds two ratios in the next larger integer type.
+    ///
+    /// Doubling the width leaves enough headroom that a single addition,
+    /// subtraction, multiplication or division of reduced ratios can never
+    /// overflow, so no `Option` is needed.
+    #[inline]
+    pub fn add_widening(&self, rhs: &Ratio<T>) -> Ratio<T::Wide> {
+        self.widened() + rhs.widened()
+    }
+
+    /// Subtracts two ratios in the next larger integer type.
+    ///
+    /// The widened type of an unsigned `T` is still unsigned, so this
+    /// keeps the usual underflow panic for negative results.
+    #[inline]
+    pub fn sub_widening(&self, rhs: &Ratio<T>) -> Ratio<T::Wide> {
+        self.widened() - rhs.widened()
+    }
+
+    /// Multiplies two ratios in the next larger integer type.
+    #[inline]
+    pub fn mul_widening(&self, rhs: &Ratio<T>) -> Ratio<T::Wide> {
+        self.widened() * rhs.widened()
+    }
+
+    /// Divides two ratios in the next larger integer type.
+    ///
+    /// **Panics if `rhs` is zero.**
+    #[inline]
+    pub fn div_widening(&self, rhs: &Ratio<T>) -> Ratio<T::Wide> {
+        self.widened() / rhs.widened()
+    }
+
+    /// Compares two ratios exactly using widening multiplication.
+    ///
+    /// `a/b` and `c/d` are ordered like `a*d` and `c*b` once the signs of the
+    /// denominators are accounted for, and the products cannot overflow in the
+    /// next larger integer type, so this avoids the division-based algorithm
+    /// that `Ord::cmp` must use for arbitrary `T` (including `i128`, `u128`
+    /// and `BigInt`, which keep the current algorithm). The result is
+    /// identical to `Ord::cmp`, including for the unreduced negative
+    /// denominators that `new_raw` can produce; denominators must be nonzero.
+    #[inline]
+    pub fn cmp_fast(&self, other: &Ratio<T>) -> cmp::Ordering {
+        // With equal denominators, the numerators can be directly compared
+        if self.denom == other.denom {
+            let ord = self.numer.cmp(&other.numer);
+            return if self.denom < T::zero() {
+                ord.reverse()
+            } else {
+                ord
+            };
+        }
+        let lhs = self.numer.clone().widen() * other.denom.clone().widen();
+        let rhs = other.numer.clone().widen() * self.denom.clone().widen();
+        let ord = lhs.cmp(&rhs);
+        if (self.denom < T::zero()) != (other.denom < T::zero()) {
+            ord.reverse()
+        } else {
+            ord
+        }
+    }
+}
+
 impl<T: Integer + Signed + Bounded + NumCast + Clone> Ratio<T> {
     pub fn approximate_float<F: FloatCore + NumCast>(f: F) -> Option<Ratio<T>> {
         // 1/10e-20 < 1/2**32 which seems like a good default, and 30 seems
@@ -1261,6 +2974,111 @@ impl<T: Integer + Signed + Bounded + NumCast + Clone> Ratio<T> {
         let epsilon = <F as NumCast>::from(10e-20).expect("Can't convert 10e-20");
         approximate_float(f, epsilon, 30)
     }
+
+    /// As `approximate_float`, but with explicit control over the maximum
+    /// error and number of continued-fraction iterations, trading precision
+    /// for denominator size per call.
+    pub fn approximate_float_bounded<F: FloatCore + NumCast>(
+        f: F,
+        max_error: F,
+        max_iterations: usize,
+    ) -> Option<Ratio<T>> {
+        approximate_float(f, max_error, max_iterations)
+    }
+
+    /// Returns the first `n` continued-fraction convergents of `f`, each of
+    /// which is a best rational approximation for its denominator size.
+    ///
+    /// Fewer than `n` convergents are returned when the expansion
+    /// terminates or further terms would overflow `T`; NaN yields an
+    /// empty vector.
+    #[cfg(feature = "std")]
+    pub fn convergents_of_f64(f: f64, n: usize) -> Vec<Ratio<T>> {
+        let negative = f.is_sign_negative();
+        convergents_unsigned(f.abs(), n)
+            .into_iter()
+            .map(|r: Ratio<T>| if negative { r.neg() } else { r })
+            .collect()
+    }
+}
+
+// Shares the recurrence of `approximate_float_unsigned`, but collects every
+// convergent instead of stopping at a target error.
+#[cfg(feature = "std")]
+fn convergents_unsigned<T, F>(val: F, max_count: usize) -> Vec<Ratio<T>>
+where
+    T: Integer + Bounded + NumCast + Clone,
+    F: FloatCore + NumCast,
+{
+    let mut convergents = Vec::new();
+
+    if val < F::zero() || val.is_nan() {
+        return convergents;
+    }
+
+    let mut q = val;
+    let mut n0 = T::zero();
+    let mut d0 = T::one();
+    let mut n1 = T::one();
+    let mut d1 = T::zero();
+
+    let t_max = T::max_value();
+    let t_max_f = match <F as NumCast>::from(t_max.clone()) {
+        Some(t_max_f) => t_max_f,
+        None => return convergents,
+    };
+
+    // 1/epsilon > T::MAX
+    let epsilon = t_max_f.recip();
+
+    // Overflow
+    if q > t_max_f {
+        return convergents;
+    }
+
+    while convergents.len() < max_count {
+        let a = match <T as NumCast>::from(q) {
+            None => break,
+            Some(a) => a,
+        };
+
+        let a_f = match <F as NumCast>::from(a.clone()) {
+            None => break,
+            Some(a_f) => a_f,
+        };
+        let f = q - a_f;
+
+        // Prevent overflow
+        if !a.is_zero()
+            && (n1 > t_max.clone() / a.clone()
+                || d1 > t_max.clone() / a.clone()
+                || a.clone() * n1.clone() > t_max.clone() - n0.clone()
+                || a.clone() * d1.clone() > t_max.clone() - d0.clone())
+        {
+            break;
+        }
+
+        let n = a.clone() * n1.clone() + n0.clone();
+        let d = a.clone() * d1.clone() + d0.clone();
+
+        n0 = n1;
+        d0 = d1;
+        n1 = n;
+        d1 = d;
+
+        if d1.is_zero() {
+            break;
+        }
+        convergents.push(Ratio::new(n1.clone(), d1.clone()));
+
+        // Prevent division by ~0
+        if f < epsilon {
+            break;
+        }
+        q = f.recip();
+    }
+
+    convergents
 }
 
 fn approximate_float<T, F>(val: F, max_error: F, max_iterations: usize) -> Option<Ratio<T>>
@@ -1444,47 +3262,289 @@ macro_rules! to_primitive_64 {
 }
 
 #[cfg(not(feature = "num-bigint"))]
-to_primitive_64!(u64 i64);
-
-#[cfg(all(target_pointer_width = "64", not(feature = "num-bigint")))]
-to_primitive_64!(usize isize);
+to_primitive_64!(u64 i64);
+
+#[cfg(all(target_pointer_width = "64", not(feature = "num-bigint")))]
+to_primitive_64!(usize isize);
+
+#[cfg(feature = "num-bigint")]
+impl<T: Clone + Integer + ToPrimitive + ToBigInt> ToPrimitive for Ratio<T> {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_integer().to_i64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.to_integer().to_i128()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_integer().to_u64()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.to_integer().to_u128()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        let float = match (self.numer.to_i64(), self.denom.to_i64()) {
+            (Some(numer), Some(denom)) => ratio_to_f64(
+                <i128 as From<_>>::from(numer),
+                <i128 as From<_>>::from(denom),
+            ),
+            _ => {
+                let numer: BigInt = self.numer.to_bigint()?;
+                let denom: BigInt = self.denom.to_bigint()?;
+                ratio_to_f64(numer, denom)
+            }
+        };
+        if float.is_nan() {
+            None
+        } else {
+            Some(float)
+        }
+    }
+}
+
+#[cfg(not(feature = "num-bigint"))]
+macro_rules! to_f64_rounding_impl {
+    ($($type_name:ty)*) => ($(
+        impl Ratio<$type_name> {
+            /// Converts to `f64` like `to_f64`, but applies `mode` when
+            /// dropping the low bits of the exact quotient, for
+            /// reproducing systems that do not round half to even.
+            pub fn to_f64_rounding(&self, mode: RoundingMode) -> Option<f64> {
+                let float = ratio_to_f64_rounding(
+                    self.numer as i128,
+                    self.denom as i128,
+                    mode
+                );
+                if float.is_nan() {
+                    None
+                } else {
+                    Some(float)
+                }
+            }
+        }
+    )*)
+}
+
+#[cfg(not(feature = "num-bigint"))]
+to_f64_rounding_impl!(u8 i8 u16 i16 u32 i32 u64 i64 usize isize);
+
+#[cfg(feature = "num-bigint")]
+impl<T: Clone + Integer + ToPrimitive + ToBigInt> Ratio<T> {
+    /// Converts to `f64` like `to_f64`, but applies `mode` when dropping
+    /// the low bits of the exact quotient, for reproducing systems that
+    /// do not round half to even.
+    pub fn to_f64_rounding(&self, mode: RoundingMode) -> Option<f64> {
+        let float = match (self.numer.to_i64(), self.denom.to_i64()) {
+            (Some(numer), Some(denom)) => ratio_to_f64_rounding(
+                <i128 as From<_>>::from(numer),
+                <i128 as From<_>>::from(denom),
+                mode,
+            ),
+            _ => {
+                let numer: BigInt = self.numer.to_bigint()?;
+                let denom: BigInt = self.denom.to_bigint()?;
+                ratio_to_f64_rounding(numer, denom, mode)
+            }
+        };
+        if float.is_nan() {
+            None
+        } else {
+            Some(float)
+        }
+    }
+}
+
+/// Compares `x` against `y * 2^d` exactly, without materializing the
+/// shifted product: a shift that would overflow means that side is larger.
+#[cfg(not(feature = "num-bigint"))]
+fn cmp_u128_shifted(x: u128, y: u128, d: i32) -> cmp::Ordering {
+    if y == 0 {
+        return if x == 0 {
+            cmp::Ordering::Equal
+        } else {
+            cmp::Ordering::Greater
+        };
+    }
+    if x == 0 {
+        return cmp::Ordering::Less;
+    }
+    if d >= 0 {
+        if (d as u32) <= y.leading_zeros() {
+            x.cmp(&(y << d))
+        } else {
+            cmp::Ordering::Less
+        }
+    } else if ((-d) as u32) <= x.leading_zeros() {
+        (x << -d).cmp(&y)
+    } else {
+        cmp::Ordering::Greater
+    }
+}
+
+#[cfg(not(feature = "num-bigint"))]
+macro_rules! cmp_f64_impl {
+    ($($type_name:ty)*) => ($(
+        impl Ratio<$type_name> {
+            /// Compares exactly against a float by decomposing it into
+            /// mantissa and exponent, instead of converting `self` to
+            /// `f64` and losing precision past 53 bits.
+            ///
+            /// Returns `None` only for NaN; infinities compare greater
+            /// (respectively less) than every ratio.
+            pub fn cmp_f64(&self, f: f64) -> Option<cmp::Ordering> {
+                if f.is_nan() {
+                    return None;
+                }
+                if f == f64::INFINITY {
+                    return Some(cmp::Ordering::Less);
+                }
+                if f == f64::NEG_INFINITY {
+                    return Some(cmp::Ordering::Greater);
+                }
+                let (mut a, mut b) = (self.numer as i128, self.denom as i128);
+                if b < 0 {
+                    a = -a;
+                    b = -b;
+                }
+                let (mantissa, exponent, sign) = f.integer_decode();
+                let float_sign = if mantissa == 0 { 0 } else { sign as i128 };
+                let ratio_sign = a.signum();
+                if ratio_sign != float_sign {
+                    return Some(ratio_sign.cmp(&float_sign));
+                }
+                if ratio_sign == 0 {
+                    return Some(cmp::Ordering::Equal);
+                }
+                // |a| / b vs mantissa * 2^exponent, multiplied through by b
+                let magnitude = cmp_u128_shifted(
+                    a.wrapping_abs() as u128,
+                    mantissa as u128 * b as u128,
+                    exponent as i32,
+                );
+                Some(if ratio_sign > 0 { magnitude } else { magnitude.reverse() })
+            }
+        }
+    )*)
+}
+
+#[cfg(not(feature = "num-bigint"))]
+cmp_f64_impl!(u8 i8 u16 i16 u32 i32 u64 i64 usize isize);
 
 #[cfg(feature = "num-bigint")]
-impl<T: Clone + Integer + ToPrimitive + ToBigInt> ToPrimitive for Ratio<T> {
-    fn to_i64(&self) -> Option<i64> {
-        self.to_integer().to_i64()
+impl<T: Clone + Integer + ToBigInt> Ratio<T> {
+    /// Compares exactly against a float by decomposing it into mantissa
+    /// and exponent, instead of converting `self` to `f64` and losing
+    /// precision past 53 bits.
+    ///
+    /// Returns `None` only for NaN (and for a numerator or denominator
+    /// that cannot convert to `BigInt`); infinities compare greater
+    /// (respectively less) than every ratio.
+    pub fn cmp_f64(&self, f: f64) -> Option<cmp::Ordering> {
+        if f.is_nan() {
+            return None;
+        }
+        if f == f64::INFINITY {
+            return Some(cmp::Ordering::Less);
+        }
+        if f == f64::NEG_INFINITY {
+            return Some(cmp::Ordering::Greater);
+        }
+        let this = Ratio::new_raw(self.numer.to_bigint()?, self.denom.to_bigint()?);
+        Some(this.cmp(&BigRational::from_float(f)?))
     }
+}
 
-    fn to_i128(&self) -> Option<i128> {
-        self.to_integer().to_i128()
-    }
+/// Iterator over the continued fraction coefficients of a ratio, created
+/// by [`Ratio::continued_fraction`].
+///
+/// Runs the Euclidean algorithm: each step yields the floor of the
+/// remaining value and continues with the reciprocal of the fractional
+/// part, until that part is zero.
+#[derive(Clone, Debug)]
+pub struct ContinuedFraction<T> {
+    numer: T,
+    denom: T,
+}
 
-    fn to_u64(&self) -> Option<u64> {
-        self.to_integer().to_u64()
+impl<T: Clone + Integer> Iterator for ContinuedFraction<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.denom.is_zero() {
+            return None;
+        }
+        let (q, r) = self.numer.div_mod_floor(&self.denom);
+        self.numer = mem::replace(&mut self.denom, r);
+        Some(q)
     }
+}
 
-    fn to_u128(&self) -> Option<u128> {
-        self.to_integer().to_u128()
+/// Linearly interpolates between `a` and `b`, computing `a + (b - a) * t`
+/// entirely in rational arithmetic.
+///
+/// `t` is typically in `[0, 1]` but is not required to be, so this also
+/// extrapolates. Staying rational avoids the precision loss of a float
+/// round-trip, e.g. when keyframing with rational timestamps.
+pub fn lerp<T: Clone + Integer>(a: &Ratio<T>, b: &Ratio<T>, t: &Ratio<T>) -> Ratio<T> {
+    a.clone() + (b.clone() - a.clone()) * t.clone()
+}
+
+/// Returns the exact harmonic mean `n / Σ(1/x)` of a slice of ratios.
+///
+/// Returns `None` for an empty slice, when any value is zero (its
+/// reciprocal is undefined), and when the reciprocals of a mixed-sign
+/// input sum to zero. The result stays exact for `BigRational`; for
+/// fixed-size `T` the reciprocal sum can overflow like any other ratio
+/// arithmetic.
+pub fn harmonic_mean<T: Clone + Integer>(values: &[Ratio<T>]) -> Option<Ratio<T>> {
+    if values.is_empty() {
+        return None;
     }
+    let mut sum = Ratio::zero();
+    let mut count = T::zero();
+    for value in values {
+        if value.is_zero() {
+            return None;
+        }
+        sum = sum + value.recip();
+        count = count + T::one();
+    }
+    if sum.is_zero() {
+        return None;
+    }
+    Some(Ratio::from_integer(count) / sum)
+}
 
-    fn to_f64(&self) -> Option<f64> {
-        let float = match (self.numer.to_i64(), self.denom.to_i64()) {
-            (Some(numer), Some(denom)) => ratio_to_f64(
-                <i128 as From<_>>::from(numer),
-                <i128 as From<_>>::from(denom),
-            ),
-            _ => {
-                let numer: BigInt = self.numer.to_bigint()?;
-                let denom: BigInt = self.denom.to_bigint()?;
-                ratio_to_f64(numer, denom)
-            }
-        };
-        if float.is_nan() {
-            None
-        } else {
-            Some(float)
+/// Returns the geometric mean of a slice of ratios as an `f64`.
+///
+/// The exact geometric mean is generally irrational, so this goes through
+/// `to_f64` and sums logarithms. Returns `None` for an empty slice, for a
+/// negative value, and when a conversion fails; a zero value makes the
+/// mean `0.0`.
+#[cfg(feature = "std")]
+pub fn geometric_mean_f64<T>(values: &[Ratio<T>]) -> Option<f64>
+where
+    T: Clone + Integer,
+    Ratio<T>: ToPrimitive,
+{
+    if values.is_empty() {
+        return None;
+    }
+    let mut log_sum = 0.0;
+    for value in values {
+        let float = value.to_f64()?;
+        if float < 0.0 {
+            return None;
         }
+        if float == 0.0 {
+            return Some(0.0);
+        }
+        log_sum += float.ln();
     }
+    Some((log_sum / values.len() as f64).exp())
 }
 
 trait Bits {
@@ -1511,6 +3571,18 @@ impl Bits for i128 {
 fn ratio_to_f64<T: Bits + Clone + Integer + Signed + ShlAssign<usize> + ToPrimitive>(
     numer: T,
     denom: T,
+) -> f64 {
+    ratio_to_f64_rounding(numer, denom, RoundingMode::HalfEven)
+}
+
+/// `ratio_to_f64` with the final rounding step parameterized.
+///
+/// Values whose magnitude over- or underflows the `f64` range saturate to
+/// `±inf` or `±0.0` regardless of the mode.
+fn ratio_to_f64_rounding<T: Bits + Clone + Integer + Signed + ShlAssign<usize> + ToPrimitive>(
+    numer: T,
+    denom: T,
+    mode: RoundingMode,
 ) -> f64 {
     assert_eq!(
         core::f64::RADIX,
@@ -1530,9 +3602,14 @@ fn ratio_to_f64<T: Bits + Clone + Integer + Signed + ShlAssign<usize> + ToPrimit
     // Fast track: both sides can losslessly be converted to f64s. In this case, letting the
     // FPU do the job is faster and easier. In any other case, converting to f64s may lead
     // to an inexact result: https://stackoverflow.com/questions/56641441/.
-    if let (Some(n), Some(d)) = (numer.to_i64(), denom.to_i64()) {
-        if MIN_EXACT_INT <= n && n <= MAX_EXACT_INT && MIN_EXACT_INT <= d && d <= MAX_EXACT_INT {
-            return n.to_f64().unwrap() / d.to_f64().unwrap();
+    // The FPU rounds to nearest-even, so the other modes must take the
+    // long-division path even for small operands.
+    if mode == RoundingMode::HalfEven {
+        if let (Some(n), Some(d)) = (numer.to_i64(), denom.to_i64()) {
+            if MIN_EXACT_INT <= n && n <= MAX_EXACT_INT && MIN_EXACT_INT <= d && d <= MAX_EXACT_INT
+            {
+                return n.to_f64().unwrap() / d.to_f64().unwrap();
+            }
         }
     }
 
@@ -1585,12 +3662,25 @@ fn ratio_to_f64<T: Bits + Clone + Integer + Signed + ShlAssign<usize> + ToPrimit
     debug_assert!(n_rounding_bits == 2 || n_rounding_bits == 3);
     let rounding_bit_mask = (1u64 << n_rounding_bits) - 1;
 
-    // Round to 53 bits with round-to-even. For rounding, we need to take into account both
+    // Round to 53 bits. For rounding, we need to take into account both
     // our rounding bits and the division's remainder.
-    let ls_bit = quotient & (1u64 << n_rounding_bits) != 0;
-    let ms_rounding_bit = quotient & (1u64 << (n_rounding_bits - 1)) != 0;
-    let ls_rounding_bits = quotient & (rounding_bit_mask >> 1) != 0;
-    if ms_rounding_bit && (ls_bit || ls_rounding_bits || !remainder.is_zero()) {
+    let dropped_nonzero = quotient & rounding_bit_mask != 0 || !remainder.is_zero();
+    let round_up = match mode {
+        RoundingMode::HalfEven => {
+            let ls_bit = quotient & (1u64 << n_rounding_bits) != 0;
+            let ms_rounding_bit = quotient & (1u64 << (n_rounding_bits - 1)) != 0;
+            let ls_rounding_bits = quotient & (rounding_bit_mask >> 1) != 0;
+            ms_rounding_bit && (ls_bit || ls_rounding_bits || !remainder.is_zero())
+        }
+        // the dropped part is at least half an ulp iff its top bit is set
+        RoundingMode::HalfUp => quotient & (1u64 << (n_rounding_bits - 1)) != 0,
+        RoundingMode::Truncate => false,
+        // quotient and shift describe the magnitude, so rounding toward an
+        // infinity bumps the magnitude only on the matching sign
+        RoundingMode::Ceil => dropped_nonzero && flo_sign > 0.0,
+        RoundingMode::Floor => dropped_nonzero && flo_sign < 0.0,
+    };
+    if round_up {
         quotient += 1u64 << n_rounding_bits;
     }
     quotient &= !rounding_bit_mask;
@@ -1623,6 +3713,8 @@ mod test {
     use core::i32;
     use core::isize;
     use core::str::FromStr;
+    #[cfg(feature = "std")]
+    use std::vec::Vec;
     use num_integer::Integer;
     use num_traits::ToPrimitive;
     use num_traits::{FromPrimitive, One, Pow, Signed, Zero};
@@ -1717,20 +3809,20 @@ mod test {
     #[test]
     fn test_test_constants() {
         // check our constants are what Ratio::new etc. would make.
-        assert_eq!(_0, Zero::zero());
-        assert_eq!(_1, One::one());
+        assert_eq!(_0, Ratio::zero());
+        assert_eq!(_1, Ratio::one());
         assert_eq!(_2, Ratio::from_integer(2));
         assert_eq!(_1_2, Ratio::new(1, 2));
         assert_eq!(_3_2, Ratio::new(3, 2));
         assert_eq!(_NEG1_2, Ratio::new(-1, 2));
-        assert_eq!(_2, From::from(2));
+        assert_eq!(_2, Ratio::from(2));
     }
 
     #[test]
     fn test_new_reduce() {
-        assert_eq!(Ratio::new(2, 2), One::one());
-        assert_eq!(Ratio::new(0, i32::MIN), Zero::zero());
-        assert_eq!(Ratio::new(i32::MIN, i32::MIN), One::one());
+        assert_eq!(Ratio::new(2, 2), Ratio::one());
+        assert_eq!(Ratio::new(0, i32::MIN), Ratio::zero());
+        assert_eq!(Ratio::new(i32::MIN, i32::MIN), Ratio::one());
     }
     #[test]
     #[should_panic]
@@ -1738,6 +3830,287 @@ mod test {
         let _a = Ratio::new(1, 0);
     }
 
+    #[test]
+    fn test_is_reduced() {
+        assert!(_0.is_reduced());
+        assert!(_1.is_reduced());
+        assert!(_1_2.is_reduced());
+        assert!(_NEG1_2.is_reduced());
+        assert!(!Ratio::new_raw(2, 4).is_reduced());
+        assert!(!Ratio::new_raw(4, 2).is_reduced());
+        assert!(Ratio::new(4, 2).is_reduced());
+        assert!(!Ratio::new_raw(0, 5).is_reduced());
+        // a negative denominator is not normal form even with gcd == 1
+        assert!(!Ratio::new_raw(1, -2).is_reduced());
+        assert!(!Ratio::new_raw(1, 0).is_reduced());
+
+        assert_eq!(Ratio::new_raw(2, 4).try_reduced(), Some(_1_2));
+        assert_eq!(Ratio::new_raw(1, -2).try_reduced(), Some(_NEG1_2));
+        assert_eq!(Ratio::new_raw(1, 0).try_reduced(), None);
+    }
+
+    #[test]
+    fn test_try_reduce() {
+        use crate::RatioErrorKind;
+
+        let mut a = Ratio::new_raw(2, 4);
+        assert_eq!(a.try_reduce(), Ok(()));
+        assert_eq!(a, _1_2);
+
+        let mut a = Ratio::new_raw(1, -2);
+        assert_eq!(a.try_reduce(), Ok(()));
+        assert_eq!(a, _NEG1_2);
+
+        // failures leave the value unchanged
+        let mut a = Ratio::new_raw(1, 0);
+        assert_eq!(a.try_reduce(), Err(RatioErrorKind::ZeroDenominator));
+        assert_eq!(a, Ratio::new_raw(1, 0));
+
+        let mut a = Ratio::new_raw(i32::min_value(), -1);
+        assert_eq!(a.try_reduce(), Err(RatioErrorKind::NegationOverflow));
+        assert_eq!(a, Ratio::new_raw(i32::min_value(), -1));
+
+        // ... but reduction can bring MIN back into range first
+        let mut a = Ratio::new_raw(i32::min_value(), -2);
+        assert_eq!(a.try_reduce(), Ok(()));
+        assert_eq!(a, Ratio::new_raw(i32::min_value() / -2, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator == 0 in addition")]
+    fn test_add_zero_denom() {
+        let _a = Ratio::new_raw(1, 0) + _1;
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator == 0 in multiplication")]
+    fn test_mul_zero_denom() {
+        let _a = Ratio::new_raw(1, 0) * _1_2;
+    }
+
+    #[test]
+    fn test_from_scientific_parts() {
+        assert_eq!(Ratio::from_scientific_parts(15, -1), _3_2);
+        assert_eq!(Ratio::from_scientific_parts(5, 3), Ratio::new(5000, 1));
+        assert_eq!(Ratio::from_scientific_parts(0, 7), _0);
+        assert_eq!(Ratio::from_scientific_parts(42, 0), Ratio::new(42, 1));
+        assert_eq!(Ratio::from_scientific_parts(-25, -2), Ratio::new(-1, 4));
+        assert_eq!(
+            Ratio::<u64>::from_scientific_parts(1, -9),
+            Ratio::new(1, 1_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_named_fractions() {
+        fn check<T: Clone + Integer>()
+        where
+            Ratio<T>: core::fmt::Debug,
+        {
+            let one: Ratio<T> = One::one();
+            assert_eq!(Ratio::<T>::half() + Ratio::<T>::half(), one);
+            assert_eq!(Ratio::<T>::third() + Ratio::<T>::two_thirds(), one);
+            assert_eq!(
+                Ratio::<T>::quarter() + Ratio::<T>::quarter(),
+                Ratio::<T>::half()
+            );
+            assert_eq!(Ratio::<T>::unit_fraction(T::zero()), None);
+            assert_eq!(
+                Ratio::<T>::unit_fraction(T::one() + T::one()),
+                Some(Ratio::<T>::half())
+            );
+        }
+        check::<u8>();
+        check::<i8>();
+        check::<i32>();
+        check::<u64>();
+
+        assert_eq!(Ratio::percent(50), _1_2);
+        assert_eq!(Ratio::<u8>::percent(50u8), Ratio::<u8>::half());
+        assert_eq!(Ratio::<u8>::percent(150u8), Ratio::new(3u8, 2));
+        assert_eq!(Ratio::percent(-25), Ratio::new(-1, 4));
+        assert_eq!(Ratio::percent(0), _0);
+    }
+
+    #[test]
+    fn test_to_f64_rounding() {
+        use super::RoundingMode;
+
+        let below = (1i64 << 53) as f64; // 2^53, even mantissa
+        let above = ((1i64 << 53) + 2) as f64; // next representable f64
+
+        // (2^53 + 1) / 2^0 is exactly between two representable values
+        let tie = Ratio::new((1i64 << 53) + 1, 1);
+        assert_eq!(tie.to_f64_rounding(RoundingMode::HalfEven), Some(below));
+        assert_eq!(tie.to_f64_rounding(RoundingMode::Truncate), Some(below));
+        assert_eq!(tie.to_f64_rounding(RoundingMode::Floor), Some(below));
+        assert_eq!(tie.to_f64_rounding(RoundingMode::Ceil), Some(above));
+        assert_eq!(tie.to_f64_rounding(RoundingMode::HalfUp), Some(above));
+        assert_eq!(tie.to_f64_rounding(RoundingMode::HalfEven), tie.to_f64());
+
+        let neg_tie = Ratio::new(-((1i64 << 53) + 1), 1);
+        assert_eq!(
+            neg_tie.to_f64_rounding(RoundingMode::HalfEven),
+            Some(-below)
+        );
+        assert_eq!(
+            neg_tie.to_f64_rounding(RoundingMode::Truncate),
+            Some(-below)
+        );
+        assert_eq!(
+            neg_tie.to_f64_rounding(RoundingMode::Floor),
+            Some(-above)
+        );
+        assert_eq!(
+            neg_tie.to_f64_rounding(RoundingMode::Ceil),
+            Some(-below)
+        );
+
+        // a tie above an odd mantissa rounds away under nearest-even
+        let odd_tie = Ratio::new((1i64 << 53) + 3, 1);
+        assert_eq!(
+            odd_tie.to_f64_rounding(RoundingMode::HalfEven),
+            Some(((1i64 << 53) + 4) as f64)
+        );
+        assert_eq!(
+            odd_tie.to_f64_rounding(RoundingMode::Truncate),
+            Some(above)
+        );
+
+        // 1/3 is not exactly representable: the directed modes straddle it
+        let third = Ratio::new(1i64, 3);
+        let down = third.to_f64_rounding(RoundingMode::Truncate).unwrap();
+        let up = third.to_f64_rounding(RoundingMode::Ceil).unwrap();
+        assert_eq!(f64::from_bits(down.to_bits() + 1), up);
+        assert_eq!(
+            third.to_f64_rounding(RoundingMode::Floor),
+            Some(down)
+        );
+    }
+
+    #[test]
+    fn test_floor_ceil_to_integer() {
+        assert_eq!(Ratio::new(7, 2).floor_to_integer(), 3);
+        assert_eq!(Ratio::new(-7, 2).floor_to_integer(), -4);
+        assert_eq!(Ratio::new(7, 2).ceil_to_integer(), 4);
+        assert_eq!(Ratio::new(-7, 2).ceil_to_integer(), -3);
+        assert_eq!(_2.floor_to_integer(), 2);
+        assert_eq!(_2.ceil_to_integer(), 2);
+        assert_eq!(_0.floor_to_integer(), 0);
+        assert_eq!(_0.ceil_to_integer(), 0);
+        assert_eq!(Ratio::new(5u8, 3).floor_to_integer(), 1);
+        assert_eq!(Ratio::new(5u8, 3).ceil_to_integer(), 2);
+    }
+
+    #[test]
+    fn test_to_integer_round() {
+        use super::RoundingMode::{Ceil, Floor, HalfEven, HalfUp, Truncate};
+
+        assert_eq!(Ratio::new(-3, 2).to_integer_round(Floor), -2);
+        assert_eq!(Ratio::new(-3, 2).to_integer_round(Ceil), -1);
+        assert_eq!(Ratio::new(-3, 2).to_integer_round(Truncate), -1);
+        assert_eq!(Ratio::new(-3, 2).to_integer_round(HalfUp), -2);
+        assert_eq!(Ratio::new(-3, 2).to_integer_round(HalfEven), -2);
+        assert_eq!(Ratio::new(5, 2).to_integer_round(HalfEven), 2);
+        assert_eq!(Ratio::new(5, 2).to_integer_round(HalfUp), 3);
+        assert_eq!(Ratio::new(7, 3).to_integer_round(Ceil), 3);
+        assert_eq!(Ratio::new(7, 3).to_integer_round(Floor), 2);
+        assert_eq!(_2.to_integer_round(HalfEven), 2);
+        assert_eq!(_0.to_integer_round(Ceil), 0);
+
+        // half-way detection stays exact at the extremes of T
+        assert_eq!(
+            _MAX.checked_to_integer_round(HalfUp),
+            Some(isize::max_value())
+        );
+        let max_and_half = Ratio::new_raw(isize::min_value() + 1, -2);
+        assert_eq!(
+            max_and_half.checked_to_integer_round(Floor),
+            Some(isize::max_value() / 2)
+        );
+
+        // `|MIN| / 1` is representable as a ratio but not as a `T`
+        let too_big = Ratio::new_raw(i8::min_value(), -1);
+        assert_eq!(too_big.checked_to_integer_round(Ceil), None);
+        assert_eq!(too_big.checked_to_integer_round(Truncate), None);
+        assert_eq!(
+            Ratio::new_raw(1, 0).checked_to_integer_round(Truncate),
+            None
+        );
+    }
+
+    #[test]
+    fn test_round_to_power_of_two() {
+        assert_eq!(_1_3.round_to_power_of_two(3), Ratio::new(3, 8));
+        assert_eq!(_1_2.round_to_power_of_two(3), _1_2);
+        assert_eq!(_NEG1_3.round_to_power_of_two(3), Ratio::new(-3, 8));
+        assert_eq!(_3_2.round_to_power_of_two(0), _2);
+        assert_eq!(_5_2.round_to_power_of_two(0), _2);
+        assert_eq!(Ratio::new(7, 2).round_to_power_of_two(0), Ratio::new(4, 1));
+        assert_eq!(_1_3.round_to_power_of_two(0), _0);
+        assert_eq!(_2_3.round_to_power_of_two(0), _1);
+        // ties go to the even multiple: 3/16 is halfway between 1/8 and 2/8
+        assert_eq!(Ratio::new(3, 16).round_to_power_of_two(3), Ratio::new(1, 4));
+        assert_eq!(Ratio::new(5, 16).round_to_power_of_two(3), Ratio::new(1, 4));
+        assert_eq!(
+            Ratio::new(-3, 16).round_to_power_of_two(3),
+            Ratio::new(-1, 4)
+        );
+        // already-dyadic values are returned exactly
+        assert_eq!(
+            Ratio::new(11, 64).round_to_power_of_two(6),
+            Ratio::new(11, 64)
+        );
+    }
+
+    #[test]
+    fn test_bounded() {
+        use num_traits::Bounded;
+
+        assert_eq!(Ratio::<isize>::max_value(), _MAX);
+        assert_eq!(Ratio::<isize>::min_value(), _MIN);
+        assert!(Ratio::<isize>::max_value() > _MAX_M1);
+        assert!(Ratio::<isize>::min_value() < _MIN_P1);
+        assert!(Ratio::<isize>::max_value() > Ratio::new(isize::max_value(), 2));
+        assert!(Ratio::<isize>::min_value() < Ratio::new(isize::min_value(), 3));
+        assert_eq!(Ratio::<u8>::min_value(), Ratio::from_integer(0u8));
+        assert_eq!(Ratio::<u8>::max_value(), Ratio::from_integer(255u8));
+    }
+
+    #[test]
+    fn test_widening_ops() {
+        let max = Ratio::<i8>::new(127, 1);
+        assert_eq!(max.add_widening(&max), Ratio::<i16>::new(254, 1));
+        assert_eq!(
+            Ratio::<i8>::new(-128, 1).sub_widening(&max),
+            Ratio::<i16>::new(-255, 1)
+        );
+        assert_eq!(
+            Ratio::<u8>::new(255, 2).mul_widening(&Ratio::new(255, 3)),
+            Ratio::<u16>::new(65025, 6)
+        );
+        assert_eq!(
+            Ratio::<i8>::new(1, 127).div_widening(&Ratio::new(126, 1)),
+            Ratio::<i16>::new(1, 16002)
+        );
+
+        // exact i128 result of a multiplication at the i64 boundary
+        let a = Ratio::<i64>::new(i64::max_value(), 2);
+        let b = Ratio::<i64>::new(i64::max_value(), 3);
+        let wide = a.mul_widening(&b);
+        let max = i64::max_value() as i128;
+        assert_eq!(wide, Ratio::<i128>::new(max * max, 6));
+
+        // cross-check against a BigRational reference
+        #[cfg(feature = "num-bigint")]
+        {
+            let big = BigRational::new(BigInt::from(i64::max_value()), BigInt::from(2))
+                * BigRational::new(BigInt::from(i64::max_value()), BigInt::from(3));
+            assert_eq!(BigInt::from(*wide.numer()), *big.numer());
+            assert_eq!(BigInt::from(*wide.denom()), *big.denom());
+        }
+    }
+
     #[test]
     fn test_approximate_float() {
         assert_eq!(Ratio::from_f32(0.5f32), Some(Ratio::new(1i64, 2)));
@@ -1780,6 +4153,72 @@ mod test {
         assert_eq!(Ratio::<i64>::from_f64(-0.0), Some(Ratio::new(0, 1)));
     }
 
+    #[test]
+    fn test_approximate_float_bounded() {
+        // With the default bounds this behaves exactly like approximate_float
+        assert_eq!(
+            Ratio::<i64>::approximate_float_bounded(0.5f32, 10e-20, 30),
+            Some(Ratio::new(1i64, 2))
+        );
+        assert_eq!(
+            Ratio::<i32>::approximate_float_bounded(29.97f64, 10e-20, 30),
+            Some(Ratio::new(2997i32, 100))
+        );
+        assert_eq!(
+            Ratio::<i64>::approximate_float_bounded(f64::NAN, 10e-20, 30),
+            None
+        );
+
+        // A loose error bound stops the continued fraction early
+        let pi = core::f64::consts::PI;
+        assert_eq!(
+            Ratio::<i64>::approximate_float_bounded(pi, 1e-2, 30),
+            Some(Ratio::new(22i64, 7))
+        );
+        assert_eq!(
+            Ratio::<i64>::approximate_float_bounded(-pi, 1e-2, 30),
+            Some(Ratio::new(-22i64, 7))
+        );
+        assert_eq!(
+            Ratio::<i64>::approximate_float_bounded(pi, 1e-5, 30),
+            Some(Ratio::new(355i64, 113))
+        );
+
+        // Iterations can be limited independently of the error bound
+        assert_eq!(
+            Ratio::<i64>::approximate_float_bounded(pi, 10e-20, 1),
+            Some(Ratio::new(3i64, 1))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_convergents_of_f64() {
+        let pi = core::f64::consts::PI;
+        assert_eq!(
+            Ratio::<i64>::convergents_of_f64(pi, 4),
+            vec![
+                Ratio::from_integer(3),
+                Ratio::new(22, 7),
+                Ratio::new(333, 106),
+                Ratio::new(355, 113),
+            ]
+        );
+        assert_eq!(
+            Ratio::<i64>::convergents_of_f64(-pi, 2),
+            vec![Ratio::from_integer(-3), Ratio::new(-22, 7)]
+        );
+
+        // a terminating expansion returns fewer than `n` convergents
+        assert_eq!(
+            Ratio::<i64>::convergents_of_f64(0.5, 10),
+            vec![Ratio::from_integer(0), Ratio::new(1, 2)]
+        );
+
+        assert!(Ratio::<i64>::convergents_of_f64(f64::NAN, 3).is_empty());
+        assert_eq!(Ratio::<i64>::convergents_of_f64(pi, 0), Vec::<Ratio<i64>>::new());
+    }
+
     #[test]
     #[allow(clippy::eq_op)]
     fn test_cmp() {
@@ -1798,6 +4237,77 @@ mod test {
         assert_eq!(_0, _0_2);
     }
 
+    #[test]
+    fn test_cmp_scalar() {
+        assert!(Ratio::new(1, 3) < 1);
+        assert!(!(Ratio::new(7, 2) == 3));
+        assert!(Ratio::new(7, 2) != 3);
+        assert!(Ratio::new(7, 2) > 3);
+        assert!(Ratio::new(6, 2) == 3);
+        assert!(_NEG1_2 < 0);
+
+        // mirrored direction for primitives
+        assert!(1 > Ratio::new(1, 3));
+        assert!(3 < Ratio::new(7, 2));
+        assert!(3 == Ratio::new(6, 2));
+
+        // near the edges of the type, where `denom * rhs` would overflow
+        assert!(_MAX > 1);
+        assert!(_MIN < -1);
+        assert!(Ratio::new(isize::max_value(), 2) < isize::max_value());
+        assert!(isize::max_value() > Ratio::new(isize::max_value(), 2));
+    }
+
+    #[test]
+    fn test_cmp_abs() {
+        use core::cmp::Ordering;
+
+        assert_eq!(Ratio::new(-3, 4).cmp_abs(&_1_2), Ordering::Greater);
+        assert_eq!(_NEG1_2.cmp_abs(&_1_2), Ordering::Equal);
+        assert_eq!(_1_2.cmp_abs(&_NEG1_2), Ordering::Equal);
+        assert_eq!(_1_3.cmp_abs(&_NEG1_2), Ordering::Less);
+        assert_eq!(_0.cmp_abs(&_0), Ordering::Equal);
+        assert_eq!(_2.cmp_abs(&-_3_2), Ordering::Greater);
+
+        // non-canonical negative denominators carry the sign too
+        let neg_denom: Rational = Ratio::new_raw(1, -2);
+        assert_eq!(neg_denom.cmp_abs(&_1_2), Ordering::Equal);
+        assert_eq!(neg_denom.cmp_abs(&_1_3), Ordering::Greater);
+
+        // near the type limits, where cross-multiplying would overflow
+        assert_eq!(_MAX.cmp_abs(&_MAX_M1), Ordering::Greater);
+        assert_eq!(_MIN_P1.cmp_abs(&_MAX), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_cmp_f64() {
+        use core::cmp::Ordering;
+
+        assert_eq!(_1_2.cmp_f64(0.5), Some(Ordering::Equal));
+        assert_eq!(_NEG1_2.cmp_f64(-0.5), Some(Ordering::Equal));
+        assert_eq!(_NEG1_2.cmp_f64(-0.25), Some(Ordering::Less));
+        assert_eq!(_0.cmp_f64(0.0), Some(Ordering::Equal));
+        assert_eq!(_0.cmp_f64(-0.0), Some(Ordering::Equal));
+        // 1/3 is just above the nearest f64
+        assert_eq!(_1_3.cmp_f64(1.0 / 3.0), Some(Ordering::Greater));
+
+        // exact past 53 bits of precision, where a conversion to f64
+        // would collapse both sides to the same value
+        let above = Ratio::from_integer((1i64 << 53) + 1);
+        assert_eq!(above.cmp_f64(9007199254740992.0), Some(Ordering::Greater));
+        let exact = Ratio::from_integer(1i64 << 53);
+        assert_eq!(exact.cmp_f64(9007199254740992.0), Some(Ordering::Equal));
+
+        // tiny magnitudes stay exact as well
+        let tiny = Ratio::new(1, isize::max_value());
+        assert_eq!(tiny.cmp_f64(f64::MIN_POSITIVE), Some(Ordering::Greater));
+        assert_eq!(tiny.cmp_f64(1.0), Some(Ordering::Less));
+
+        assert_eq!(_1.cmp_f64(f64::NAN), None);
+        assert_eq!(_1.cmp_f64(f64::INFINITY), Some(Ordering::Less));
+        assert_eq!(_1.cmp_f64(f64::NEG_INFINITY), Some(Ordering::Greater));
+    }
+
     #[test]
     fn test_cmp_overflow() {
         use core::cmp::Ordering;
@@ -1823,6 +4333,8 @@ mod test {
             println!("comparing {} and {}", a, b);
             assert_eq!(a.cmp(&b), ord);
             assert_eq!(b.cmp(&a), ord.reverse());
+            assert_eq!(a.cmp_fast(&b), ord);
+            assert_eq!(b.cmp_fast(&a), ord.reverse());
         }
 
         for (i, &a) in ratios.iter().enumerate() {
@@ -1837,6 +4349,79 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_cmp_fast_differential() {
+        // xorshift64: deterministic, no external dependency
+        fn next(state: &mut u64) -> u64 {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            *state
+        }
+
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        // `MIN` is excluded: the generic algorithm's floored divisions
+        // overflow on `MIN / -1`, which is a pre-existing limitation.
+        let mut gen_i8 = |state: &mut u64| loop {
+            let v = next(state) as i8;
+            if v != i8::min_value() {
+                return v;
+            }
+        };
+        // Small components hit the equal-numerator/denominator shortcuts
+        // and produce Equal results often.
+        for _ in 0..100_000 {
+            let an = gen_i8(&mut state);
+            let ad = loop {
+                let v = gen_i8(&mut state);
+                if v != 0 {
+                    break v;
+                }
+            };
+            let bn = gen_i8(&mut state);
+            let bd = loop {
+                let v = gen_i8(&mut state);
+                if v != 0 {
+                    break v;
+                }
+            };
+            let a = Ratio::new_raw(an, ad);
+            let b = Ratio::new_raw(bn, bd);
+            assert_eq!(a.cmp_fast(&b), a.cmp(&b));
+        }
+
+        let mut gen_i64 = |state: &mut u64| loop {
+            let v = next(state) as i64;
+            if v != i64::min_value() {
+                return v;
+            }
+        };
+        for _ in 0..100_000 {
+            let an = gen_i64(&mut state);
+            let ad = loop {
+                let v = gen_i64(&mut state);
+                if v != 0 {
+                    break v;
+                }
+            };
+            let bn = gen_i64(&mut state);
+            let bd = loop {
+                let v = gen_i64(&mut state);
+                if v != 0 {
+                    break v;
+                }
+            };
+            let a = Ratio::new_raw(an, ad);
+            let b = Ratio::new_raw(bn, bd);
+            assert_eq!(a.cmp_fast(&b), a.cmp(&b));
+        }
+        for _ in 0..100_000 {
+            let a = Ratio::new_raw(next(&mut state), next(&mut state) | 1);
+            let b = Ratio::new_raw(next(&mut state), next(&mut state) | 1);
+            assert_eq!(a.cmp_fast(&b), a.cmp(&b));
+        }
+    }
+
     #[test]
     fn test_to_integer() {
         assert_eq!(_0.to_integer(), 0);
@@ -1876,6 +4461,312 @@ mod test {
         assert!(!_NEG1_2.is_integer());
     }
 
+    #[test]
+    fn test_scale_to_denominator() {
+        let third = Ratio::new(1, 3);
+        let scaled = third.scale_to_denominator(12).unwrap();
+        // unreduced: the target denominator is preserved verbatim
+        assert_eq!((*scaled.numer(), *scaled.denom()), (4, 12));
+
+        assert_eq!(third.scale_to_denominator(10), None);
+        assert_eq!(third.scale_to_denominator(0), None);
+
+        let neg = Ratio::new(-2, 5).scale_to_denominator(15).unwrap();
+        assert_eq!((*neg.numer(), *neg.denom()), (-6, 15));
+
+        let unsigned = Ratio::new(3u32, 4).scale_to_denominator(8u32).unwrap();
+        assert_eq!((*unsigned.numer(), *unsigned.denom()), (6, 8));
+    }
+
+    #[test]
+    fn test_try_new_into_raw() {
+        assert_eq!(Ratio::try_new(4, 2), Some(Ratio::new(2, 1)));
+        assert_eq!(Ratio::try_new(1, 0), None);
+        assert_eq!(Ratio::try_new(0, 5), Some(_0));
+        assert_eq!(Ratio::try_new(-3, 6), Some(Ratio::new(-1, 2)));
+
+        let (numer, denom) = Ratio::new(4, 2).into_raw();
+        assert_eq!((numer, denom), (2, 1));
+        // new_raw parts come back unreduced
+        assert_eq!(Ratio::new_raw(4, 2).into_raw(), (4, 2));
+    }
+
+    #[test]
+    fn test_checked_new() {
+        assert_eq!(Ratio::checked_new(1, 0), None);
+        assert_eq!(Ratio::checked_new(2, 4), Some(_1_2));
+        // sign normalization moves the sign to the numerator
+        assert_eq!(Ratio::checked_new(1, -2), Some(-_1_2));
+        assert_eq!(
+            Ratio::checked_new(i32::min_value(), 2),
+            Some(Ratio::new_raw(i32::min_value() / 2, 1))
+        );
+        assert_eq!(
+            Ratio::checked_new(i32::min_value(), i32::min_value()),
+            Some(Ratio::new_raw(1, 1))
+        );
+    }
+
+    #[test]
+    fn test_checked_from_pair() {
+        // ordinary pairs behave like `new`
+        assert_eq!(Ratio::checked_from_pair(2, 4), Some(_1_2));
+        assert_eq!(Ratio::checked_from_pair(1, -2), Some(-_1_2));
+        assert_eq!(Ratio::checked_from_pair(0, -7), Some(_0));
+        assert_eq!(Ratio::checked_from_pair(1, 0), None);
+        assert_eq!(Ratio::checked_from_pair(3u8, 6), Some(Ratio::new_raw(1, 2)));
+
+        // sign normalization of the reduced MIN numerator would overflow,
+        // where `new` panics
+        assert_eq!(Ratio::<i32>::checked_from_pair(i32::min_value(), -1), None);
+        assert_eq!(Ratio::<i32>::checked_from_pair(1, i32::min_value()), None);
+        // ... but reduction can bring it back into range first
+        assert_eq!(
+            Ratio::checked_from_pair(i32::min_value(), -2),
+            Some(Ratio::new_raw(i32::min_value() / -2, 1))
+        );
+        assert_eq!(
+            Ratio::checked_from_pair(i32::min_value(), i32::min_value()),
+            Some(Ratio::new_raw(1, 1))
+        );
+    }
+
+    #[test]
+    fn test_width_conversions() {
+        use core::convert::TryFrom;
+
+        // widening preserves the exact components
+        let small = Ratio::new(-3i32, 4);
+        let wide = Ratio::<i64>::from(small);
+        assert_eq!((*wide.numer(), *wide.denom()), (-3i64, 4));
+        // ... and narrowing round-trips it
+        assert_eq!(Ratio::<i32>::try_from(wide), Ok(small));
+
+        let wide = Ratio::<u128>::from(Ratio::new(3u8, 200));
+        assert_eq!((*wide.numer(), *wide.denom()), (3u128, 200));
+
+        // a component out of range fails narrowing
+        assert!(Ratio::<i32>::try_from(Ratio::new(1i64, i64::max_value())).is_err());
+        assert!(Ratio::<i8>::try_from(Ratio::new(-129i16, 1)).is_err());
+
+        #[cfg(feature = "num-bigint")]
+        {
+            let big = BigRational::from(Ratio::new(-3i32, 4));
+            assert_eq!(big, Ratio::new(BigInt::from(-3), BigInt::from(4)));
+            assert_eq!(Ratio::<i64>::try_from(big), Ok(Ratio::new(-3i64, 4)));
+
+            let huge = BigRational::from_integer(BigInt::from(u128::max_value()));
+            assert!(Ratio::<i64>::try_from(huge).is_err());
+        }
+    }
+
+    #[test]
+    fn test_lerp() {
+        use crate::lerp;
+
+        assert_eq!(lerp(&_0, &Ratio::from_integer(10), &_1_2), Ratio::from_integer(5));
+        assert_eq!(lerp(&_2, &Ratio::from_integer(4), &Ratio::new(1, 4)), _5_2);
+        assert_eq!(lerp(&_0, &_1, &_0), _0);
+        assert_eq!(lerp(&_0, &_1, &_1), _1);
+        // extrapolation beyond [0, 1]
+        assert_eq!(lerp(&_0, &_2, &_2), Ratio::from_integer(4));
+        assert_eq!(lerp(&_1, &_2, &-_1), _0);
+        // exact where floats would round
+        let third = Ratio::new(1, 3);
+        assert_eq!(lerp(&_0, &third, &third), Ratio::new(1, 9));
+    }
+
+    #[test]
+    fn test_continued_fraction() {
+        // fixed-size expectations keep this test alloc-free for no_std runs
+        assert!(Ratio::new(355, 113).continued_fraction().eq([3, 7, 16]));
+        assert!(_0.continued_fraction().eq([0]));
+        assert!(_3_2.continued_fraction().eq([1, 2]));
+        // floor convention: only the leading coefficient may be negative
+        assert!(Ratio::new(-7, 2).continued_fraction().eq([-4, 2]));
+        assert!(_NEG1_2.continued_fraction().eq([-1, 2]));
+
+        assert_eq!(
+            Ratio::from_continued_fraction([2]),
+            Some(Ratio::from_integer(2))
+        );
+        assert_eq!(
+            Ratio::from_continued_fraction([3, 7, 16]),
+            Some(Ratio::new(355, 113))
+        );
+        assert_eq!(Ratio::<i32>::from_continued_fraction([]), None);
+        // convergent numerators overflow
+        assert_eq!(
+            Ratio::<i8>::from_continued_fraction([100, 100]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_continued_fraction_roundtrip() {
+        // xorshift64: deterministic, no external dependency
+        fn next(state: &mut u64) -> u64 {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            *state
+        }
+
+        let mut state = 0x0123_4567_89ab_cdef_u64;
+        // `MIN` is excluded: the floored divisions in the expansion
+        // overflow on `MIN / -1`, which is a pre-existing limitation.
+        let mut gen = |state: &mut u64| loop {
+            let v = next(state) as i64;
+            if v != i64::min_value() {
+                return v;
+            }
+        };
+        for _ in 0..10_000 {
+            let numer = gen(&mut state);
+            let denom = loop {
+                let v = gen(&mut state);
+                if v != 0 {
+                    break v;
+                }
+            };
+            let ratio = Ratio::new(numer, denom);
+            let rebuilt = Ratio::from_continued_fraction(ratio.continued_fraction());
+            assert_eq!(rebuilt, Some(ratio), "{}/{}", numer, denom);
+        }
+    }
+
+    #[test]
+    fn test_harmonic_mean() {
+        use crate::harmonic_mean;
+
+        let values = [Ratio::from_integer(1), Ratio::from_integer(2), Ratio::from_integer(3)];
+        assert_eq!(harmonic_mean(&values), Some(Ratio::new(18, 11)));
+        let values = [_1, _1_2, Ratio::new(1, 3)];
+        assert_eq!(harmonic_mean(&values), Some(_1_2));
+
+        assert_eq!(harmonic_mean::<i32>(&[]), None);
+        assert_eq!(harmonic_mean(&[_1, _0]), None);
+        // mixed signs whose reciprocals cancel leave the mean undefined
+        assert_eq!(harmonic_mean(&[_1, -_1]), None);
+        assert_eq!(harmonic_mean(&[_1_3, _NEG1_2, -_1]), None);
+
+        #[cfg(feature = "num-bigint")]
+        {
+            // exact for BigRational
+            let values: Vec<BigRational> = (1..=50)
+                .map(|i| Ratio::from_integer(BigInt::from(i)))
+                .collect();
+            let mean = harmonic_mean(&values).unwrap();
+            let mut recip_sum = BigRational::zero();
+            for v in &values {
+                recip_sum = recip_sum + v.recip();
+            }
+            assert_eq!(mean * recip_sum, Ratio::from_integer(BigInt::from(50)));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_geometric_mean_f64() {
+        use crate::geometric_mean_f64;
+
+        let values = [Ratio::from_integer(2), Ratio::from_integer(8)];
+        assert_eq!(geometric_mean_f64(&values), Some(4.0));
+        assert_eq!(geometric_mean_f64(&[_1_2, _2]), Some(1.0));
+        assert_eq!(geometric_mean_f64(&[_0, _2]), Some(0.0));
+        assert_eq!(geometric_mean_f64(&[_NEG1_2, _2]), None);
+        assert_eq!(geometric_mean_f64::<i32>(&[]), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_across_widths() {
+        use serde_test::{assert_tokens, Token};
+
+        // the tuple format is identical for every integer width
+        assert_tokens(
+            &Ratio::new(3i32, 4),
+            &[
+                Token::Tuple { len: 2 },
+                Token::I32(3),
+                Token::I32(4),
+                Token::TupleEnd,
+            ],
+        );
+        assert_tokens(
+            &Ratio::new(3i64, 4),
+            &[
+                Token::Tuple { len: 2 },
+                Token::I64(3),
+                Token::I64(4),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_abs_ratio() {
+        // unsigned: abs is the identity, nothing is ever negative
+        let u = Ratio::new(3u32, 4);
+        assert_eq!(u.abs_ratio(), u);
+        assert!(u.is_positive_ratio());
+        assert!(!u.is_negative_ratio());
+        let zero_u = Ratio::new(0u32, 1);
+        assert_eq!(zero_u.abs_ratio(), zero_u);
+        assert!(!zero_u.is_positive_ratio());
+        assert!(!zero_u.is_negative_ratio());
+
+        // signed
+        assert_eq!(_NEG1_2.abs_ratio(), _1_2);
+        assert_eq!(_1_2.abs_ratio(), _1_2);
+        assert_eq!(_0.abs_ratio(), _0);
+        assert!(_NEG1_2.is_negative_ratio());
+        assert!(!_NEG1_2.is_positive_ratio());
+        assert!(_1_2.is_positive_ratio());
+        assert!(!_0.is_positive_ratio());
+        assert!(!_0.is_negative_ratio());
+
+        // a negative denominator from new_raw is also normalized away
+        assert_eq!(Ratio::new_raw(1, -2).abs_ratio(), _1_2);
+        assert!(Ratio::new_raw(1, -2).is_negative_ratio());
+        assert!(Ratio::new_raw(-1, -2).is_positive_ratio());
+    }
+
+    #[test]
+    fn test_try_from_float() {
+        use core::convert::TryFrom;
+        use crate::TryFromFloatError;
+
+        assert_eq!(Ratio::<i64>::try_from(0.5f64), Ok(Ratio::new(1i64, 2)));
+        // 0.1 is not representable in binary; the conversion is exact, not
+        // the "nice" approximation FromPrimitive would produce
+        assert_eq!(
+            Ratio::<i64>::try_from(0.1f64),
+            Ok(Ratio::new(3602879701896397i64, 36028797018963968))
+        );
+        assert_eq!(Ratio::<i64>::try_from(-2.25f64), Ok(Ratio::new(-9i64, 4)));
+        assert_eq!(Ratio::<i64>::try_from(0.0f64), Ok(Ratio::new(0i64, 1)));
+        assert_eq!(Ratio::<u32>::try_from(0.75f32), Ok(Ratio::new(3u32, 4)));
+        assert_eq!(Ratio::<i8>::try_from(0.5f32), Ok(Ratio::new(1i8, 2)));
+
+        assert_eq!(
+            Ratio::<i64>::try_from(1e300f64),
+            Err(TryFromFloatError::Overflow)
+        );
+        assert_eq!(
+            Ratio::<u8>::try_from(-0.5f64),
+            Err(TryFromFloatError::Overflow)
+        );
+        assert_eq!(
+            Ratio::<i64>::try_from(f64::NAN),
+            Err(TryFromFloatError::NotFinite)
+        );
+        assert_eq!(
+            Ratio::<i64>::try_from(f64::INFINITY),
+            Err(TryFromFloatError::NotFinite)
+        );
+    }
+
     #[cfg(not(feature = "std"))]
     use core::fmt::{self, Write};
     #[cfg(not(feature = "std"))]
@@ -1946,6 +4837,25 @@ mod test {
         };
     }
 
+    #[test]
+    fn test_show_mixed() {
+        // alternate Display renders mixed numbers
+        assert_fmt_eq!(format_args!("{:#}", Ratio::new(7, 3)), "2 1/3");
+        assert_fmt_eq!(format_args!("{:#}", Ratio::new(-7, 3)), "-2 1/3");
+        // proper fractions have no whole part
+        assert_fmt_eq!(format_args!("{:#}", _1_2), "1/2");
+        assert_fmt_eq!(format_args!("{:#}", _NEG1_2), "-1/2");
+        // exact integers drop the fractional part
+        assert_fmt_eq!(format_args!("{:#}", Ratio::new(4, 2)), "2");
+        assert_fmt_eq!(format_args!("{:#}", _0), "0");
+        // plain Display is unchanged
+        assert_fmt_eq!(format_args!("{}", Ratio::new(7, 3)), "7/3");
+        // sign and width flags still go through pad_integral
+        assert_fmt_eq!(format_args!("{:+#}", Ratio::new(7, 3)), "+2 1/3");
+        assert_fmt_eq!(format_args!("{:>#8}", Ratio::new(7, 3)), "   2 1/3");
+        assert_fmt_eq!(format_args!("{:+#}", Ratio::new(-7, 3)), "-2 1/3");
+    }
+
     #[test]
     fn test_show() {
         // Test:
@@ -1969,25 +4879,22 @@ mod test {
         assert_fmt_eq!(format_args!("{:-b}", _1_2), "1/10");
         assert_fmt_eq!(format_args!("{:b}", _0), "0");
         assert_fmt_eq!(format_args!("{:#b}", _1_2), "0b1/0b10");
-        // no std does not support padding
-        #[cfg(feature = "std")]
-        assert_eq!(&format!("{:010b}", _1_2), "0000001/10");
-        #[cfg(feature = "std")]
-        assert_eq!(&format!("{:#010b}", _1_2), "0b001/0b10");
+        // padding via pad_integral works in both std and no_std
+        assert_fmt_eq!(format_args!("{:010b}", _1_2), "0000001/10");
+        assert_fmt_eq!(format_args!("{:#010b}", _1_2), "0b001/0b10");
+        assert_fmt_eq!(format_args!("{:>8b}", _1_2), "    1/10");
+        assert_fmt_eq!(format_args!("{:*<8b}", _1_2), "1/10****");
         let half_i8: Ratio<i8> = Ratio::new(1_i8, 2_i8);
         assert_fmt_eq!(format_args!("{:b}", -half_i8), "11111111/10");
         assert_fmt_eq!(format_args!("{:#b}", -half_i8), "0b11111111/0b10");
-        #[cfg(feature = "std")]
-        assert_eq!(&format!("{:05}", Ratio::new(-1_i8, 1_i8)), "-0001");
+        assert_fmt_eq!(format_args!("{:05}", Ratio::new(-1_i8, 1_i8)), "-0001");
 
         assert_fmt_eq!(format_args!("{:o}", _8), "10");
         assert_fmt_eq!(format_args!("{:o}", _1_8), "1/10");
         assert_fmt_eq!(format_args!("{:o}", _0), "0");
         assert_fmt_eq!(format_args!("{:#o}", _1_8), "0o1/0o10");
-        #[cfg(feature = "std")]
-        assert_eq!(&format!("{:010o}", _1_8), "0000001/10");
-        #[cfg(feature = "std")]
-        assert_eq!(&format!("{:#010o}", _1_8), "0o001/0o10");
+        assert_fmt_eq!(format_args!("{:010o}", _1_8), "0000001/10");
+        assert_fmt_eq!(format_args!("{:#010o}", _1_8), "0o001/0o10");
         assert_fmt_eq!(format_args!("{:o}", -half_i8), "377/2");
         assert_fmt_eq!(format_args!("{:#o}", -half_i8), "0o377/0o2");
 
@@ -1997,10 +4904,8 @@ mod test {
         assert_fmt_eq!(format_args!("{:x}", _1_15), "1/f");
         assert_fmt_eq!(format_args!("{:x}", _0), "0");
         assert_fmt_eq!(format_args!("{:#x}", _1_16), "0x1/0x10");
-        #[cfg(feature = "std")]
-        assert_eq!(&format!("{:010x}", _1_16), "0000001/10");
-        #[cfg(feature = "std")]
-        assert_eq!(&format!("{:#010x}", _1_16), "0x001/0x10");
+        assert_fmt_eq!(format_args!("{:010x}", _1_16), "0000001/10");
+        assert_fmt_eq!(format_args!("{:#010x}", _1_16), "0x001/0x10");
         assert_fmt_eq!(format_args!("{:x}", -half_i8), "ff/2");
         assert_fmt_eq!(format_args!("{:#x}", -half_i8), "0xff/0x2");
 
@@ -2010,10 +4915,8 @@ mod test {
         assert_fmt_eq!(format_args!("{:X}", _1_15), "1/F");
         assert_fmt_eq!(format_args!("{:X}", _0), "0");
         assert_fmt_eq!(format_args!("{:#X}", _1_16), "0x1/0x10");
-        #[cfg(feature = "std")]
-        assert_eq!(format!("{:010X}", _1_16), "0000001/10");
-        #[cfg(feature = "std")]
-        assert_eq!(format!("{:#010X}", _1_16), "0x001/0x10");
+        assert_fmt_eq!(format_args!("{:010X}", _1_16), "0000001/10");
+        assert_fmt_eq!(format_args!("{:#010X}", _1_16), "0x001/0x10");
         assert_fmt_eq!(format_args!("{:X}", -half_i8), "FF/2");
         assert_fmt_eq!(format_args!("{:#X}", -half_i8), "0xFF/0x2");
 
@@ -2464,6 +5367,36 @@ mod test {
             assert_eq!(_1.checked_div(&_0), None);
         }
 
+        #[test]
+        fn test_checked_assign() {
+            let mut a = _1_2;
+            assert!(a.checked_add_assign(&_1_2));
+            assert_eq!(a, _1);
+            assert!(a.checked_sub_assign(&_1_2));
+            assert_eq!(a, _1_2);
+            assert!(a.checked_mul_assign(&_2));
+            assert_eq!(a, _1);
+            assert!(a.checked_div_assign(&_2));
+            assert_eq!(a, _1_2);
+
+            // Failed operations must leave the value untouched
+            let big = Ratio::new(128u8, 1);
+            let small = Ratio::new(1, 128u8);
+            let mut x = big;
+            assert!(!x.checked_add_assign(&big));
+            assert_eq!(x, big);
+            assert!(!x.checked_mul_assign(&big));
+            assert_eq!(x, big);
+            let mut y = small;
+            assert!(!y.checked_sub_assign(&big));
+            assert_eq!(y, small);
+            assert!(!y.checked_div_assign(&big));
+            assert_eq!(y, small);
+            let mut z = _1;
+            assert!(!z.checked_div_assign(&_0));
+            assert_eq!(z, _1);
+        }
+
         #[test]
         fn test_checked_zeros() {
             assert_eq!(_0.checked_add(&_0), Some(_0));
@@ -2534,6 +5467,48 @@ mod test {
             assert_eq!(_MAX.checked_mul(&_MIN), None);
             assert_eq!(_MAX.checked_div(&_MIN), None);
         }
+
+        #[test]
+        fn test_saturating() {
+            // non-overflowing results are exact
+            assert_eq!(_1_2.saturating_add(&_1_2), _1);
+            assert_eq!(_1_2.saturating_sub(&_1), -_1_2);
+            assert_eq!(_3_2.saturating_mul(&_2), Ratio::new(3, 1));
+
+            // overflow clamps towards the sign of the true result
+            assert_eq!(_MAX.saturating_add(&_1), _MAX);
+            assert_eq!(_MAX.saturating_add(&_MAX), _MAX);
+            assert_eq!(_MIN.saturating_add(&-_1), _MIN);
+            assert_eq!(_MIN.saturating_sub(&_1), _MIN);
+            assert_eq!(_MAX.saturating_sub(&_MIN), _MAX);
+            assert_eq!(_MIN.saturating_sub(&_MAX), _MIN);
+            assert_eq!(_MAX.saturating_mul(&_MAX), _MAX);
+            assert_eq!(_MAX.saturating_mul(&_MIN), _MIN);
+            assert_eq!(_MIN.saturating_mul(&_MIN), _MAX);
+
+            // mixed signs where only the common denominator overflows:
+            // the true result is tiny but saturation picks its sign
+            let a = Ratio::new(1, i32::max_value());
+            let b = Ratio::new(-1, i32::max_value() - 1);
+            assert_eq!(a.checked_add(&b), None);
+            assert_eq!(
+                a.saturating_add(&b),
+                Ratio::from_integer(i32::min_value())
+            );
+            assert_eq!(
+                b.saturating_add(&a),
+                Ratio::from_integer(i32::min_value())
+            );
+            assert_eq!(
+                a.saturating_sub(&b),
+                Ratio::from_integer(i32::max_value())
+            );
+            // unsigned subtraction clamps a negative result to zero
+            let small = Ratio::new(1u8, 128);
+            let big = Ratio::new(128u8, 1);
+            assert_eq!(small.saturating_sub(&big), Ratio::from_integer(0u8));
+            assert_eq!(big.saturating_add(&big), Ratio::from_integer(u8::max_value()));
+        }
     }
 
     #[test]
@@ -2585,14 +5560,14 @@ mod test {
         let _large_rat7 = Ratio::new(1, i32::MIN + 1);
         let _large_rat8 = Ratio::new(1, i32::MAX);
 
-        assert_eq!(_large_rat1.round(), One::one());
-        assert_eq!(_large_rat2.round(), One::one());
-        assert_eq!(_large_rat3.round(), One::one());
-        assert_eq!(_large_rat4.round(), One::one());
+        assert_eq!(_large_rat1.round(), Ratio::one());
+        assert_eq!(_large_rat2.round(), Ratio::one());
+        assert_eq!(_large_rat3.round(), Ratio::one());
+        assert_eq!(_large_rat4.round(), Ratio::one());
         assert_eq!(_large_rat5.round(), _neg1);
         assert_eq!(_large_rat6.round(), _neg1);
-        assert_eq!(_large_rat7.round(), Zero::zero());
-        assert_eq!(_large_rat8.round(), Zero::zero());
+        assert_eq!(_large_rat7.round(), Ratio::zero());
+        assert_eq!(_large_rat8.round(), Ratio::zero());
     }
 
     #[test]
@@ -2603,6 +5578,16 @@ mod test {
         assert_eq!(_3_2.fract(), _1_2);
     }
 
+    #[test]
+    fn test_as_mixed_number() {
+        assert_eq!(Ratio::new(7, 2).as_mixed_number(), (3, _1_2));
+        assert_eq!(Ratio::new(-7, 2).as_mixed_number(), (-3, _NEG1_2));
+        assert_eq!(_1_2.as_mixed_number(), (0, _1_2));
+        assert_eq!(_2.as_mixed_number(), (2, _0));
+        let (int, fract) = Ratio::new(-9, 4).as_mixed_number();
+        assert_eq!(Ratio::from_integer(int) + fract, Ratio::new(-9, 4));
+    }
+
     #[test]
     fn test_recip() {
         assert_eq!(_1 * _1.recip(), _1);
@@ -2616,12 +5601,201 @@ mod test {
         assert_eq!(_NEG1_2.recip().denom(), &1);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_to_decimal_string() {
+        use std::string::ToString;
+
+        assert_eq!(Ratio::new(1, 3).to_decimal_string(10), "0.(3)");
+        assert_eq!(Ratio::new(1, 7).to_decimal_string(10), "0.(142857)");
+        assert_eq!(Ratio::new(1, 8).to_decimal_string(10), "0.125");
+        assert_eq!(Ratio::new(22, 7).to_decimal_string(10), "3.(142857)");
+        assert_eq!(Ratio::new(-22, 7).to_decimal_string(10), "-3.(142857)");
+        assert_eq!(Ratio::new(1, -3).to_decimal_string(10), "-0.(3)");
+        assert_eq!(Ratio::new(-1, -3).to_decimal_string(10), "0.(3)");
+
+        // Integers and zero have no fractional part at all
+        assert_eq!(_0.to_decimal_string(10), "0");
+        assert_eq!(_NEG2.to_decimal_string(10), "-2");
+        assert_eq!(_BILLION.to_decimal_string(10), "1000000000");
+
+        // Decimal prefixes before the cycle are kept outside the parens
+        assert_eq!(Ratio::new(1, 6).to_decimal_string(10), "0.1(6)");
+
+        // Truncation when the cycle exceeds the digit limit
+        assert_eq!(Ratio::new(1, 7).to_decimal_string(3), "0.142…".to_string());
+        assert_eq!(Ratio::new(1, 8).to_decimal_string(2), "0.12…");
+
+        #[cfg(feature = "num-bigint")]
+        assert_eq!(
+            BigRational::new(1.into(), 17.into()).to_decimal_string(20),
+            "0.(0588235294117647)"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "num-bigint"))]
+    fn test_from_decimal_str() {
+        use super::Ratio as R;
+
+        fn big(numer: i64, denom: i64) -> BigRational {
+            R::new(numer.into(), denom.into())
+        }
+
+        assert_eq!(BigRational::from_decimal_str("0.1"), Ok(big(1, 10)));
+        // ... which from_float cannot do exactly
+        assert_ne!(Ratio::from_float(0.1f64), Some(big(1, 10)));
+
+        assert_eq!(BigRational::from_decimal_str("3.14"), Ok(big(157, 50)));
+        assert_eq!(BigRational::from_decimal_str("6.022e2"), Ok(big(3011, 5)));
+        assert_eq!(BigRational::from_decimal_str("1.5e-3"), Ok(big(3, 2000)));
+        assert_eq!(BigRational::from_decimal_str("123.456e-2"), Ok(big(15432, 12500)));
+        assert_eq!(BigRational::from_decimal_str("123.456E-2"), Ok(big(15432, 12500)));
+        assert_eq!(BigRational::from_decimal_str("-2.5"), Ok(big(-5, 2)));
+        assert_eq!(BigRational::from_decimal_str("+2.5"), Ok(big(5, 2)));
+        assert_eq!(BigRational::from_decimal_str("42"), Ok(big(42, 1)));
+        assert_eq!(BigRational::from_decimal_str(".5"), Ok(big(1, 2)));
+        assert_eq!(BigRational::from_decimal_str("5."), Ok(big(5, 1)));
+        assert_eq!(BigRational::from_decimal_str("1e3"), Ok(big(1000, 1)));
+        assert_eq!(BigRational::from_decimal_str("1e+3"), Ok(big(1000, 1)));
+
+        for invalid in &["", ".", "e5", "1.2.3", "1/2", "0x10", "1e", "--1", "1 "] {
+            assert!(
+                BigRational::from_decimal_str(invalid).is_err(),
+                "accepted {:?}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "num-bigint"))]
+    fn test_to_decimal_string_rounded() {
+        use super::RoundingMode::*;
+
+        fn big(numer: i64, denom: i64) -> BigRational {
+            Ratio::new(numer.into(), denom.into())
+        }
+
+        assert_eq!(big(1, 3).to_decimal_string_rounded(5, Truncate), "0.33333");
+        assert_eq!(big(2, 3).to_decimal_string_rounded(2, HalfUp), "0.67");
+        assert_eq!(big(2, 3).to_decimal_string_rounded(2, Truncate), "0.66");
+        assert_eq!(big(-1, 3).to_decimal_string_rounded(5, Floor), "-0.33334");
+        assert_eq!(big(-1, 3).to_decimal_string_rounded(5, Ceil), "-0.33333");
+
+        // Exact expansions are printed without padding
+        assert_eq!(big(1, 8).to_decimal_string_rounded(5, Truncate), "0.125");
+        assert_eq!(big(2, 1).to_decimal_string_rounded(5, HalfUp), "2");
+        assert_eq!(big(9999, 10000).to_decimal_string_rounded(4, HalfUp), "0.9999");
+
+        // Carries across the decimal point keep the digit count
+        assert_eq!(big(99999, 100000).to_decimal_string_rounded(4, HalfUp), "1.0000");
+        assert_eq!(big(-99999, 100000).to_decimal_string_rounded(4, HalfUp), "-1.0000");
+
+        // Ties
+        assert_eq!(big(1, 2).to_decimal_string_rounded(0, HalfUp), "1");
+        assert_eq!(big(1, 2).to_decimal_string_rounded(0, HalfEven), "0");
+        assert_eq!(big(3, 2).to_decimal_string_rounded(0, HalfEven), "2");
+        assert_eq!(big(-1, 2).to_decimal_string_rounded(0, HalfUp), "-1");
+
+        // Values that round to zero don't keep a stray sign
+        assert_eq!(big(-1, 3).to_decimal_string_rounded(0, Truncate), "0");
+    }
+
+    #[test]
+    fn test_checked_recip() {
+        assert_eq!(_1_2.checked_recip(), Some(_2));
+        assert_eq!(
+            Ratio::new(-2, 3).checked_recip(),
+            Some(Ratio::new(-3, 2))
+        );
+        assert_eq!(_0.checked_recip(), None);
+        assert_eq!(Ratio::<i8>::new(-128, 1).checked_recip(), None);
+        assert_eq!(
+            Ratio::<i8>::new(1, 127).checked_recip(),
+            Some(Ratio::new(127, 1))
+        );
+    }
+
+    #[test]
+    fn test_checked_neg() {
+        assert_eq!(
+            Ratio::new(3, 4).checked_neg(),
+            Some(Ratio::new(-3, 4))
+        );
+        assert_eq!(Ratio::new(-3, 4).checked_neg(), Some(Ratio::new(3, 4)));
+        assert_eq!(_0.checked_neg(), Some(_0));
+        assert_eq!(Ratio::new(i32::min_value(), 1).checked_neg(), None);
+        assert_eq!(_MIN.checked_neg(), None);
+        assert_eq!(_MIN_P1.checked_neg(), Some(_MAX));
+        assert_eq!(Ratio::new(0u8, 1).checked_neg(), Some(Ratio::new(0u8, 1)));
+        assert_eq!(Ratio::new(1u8, 2).checked_neg(), None);
+    }
+
     #[test]
     #[should_panic(expected = "division by zero")]
     fn test_recip_fail() {
         let _a = Ratio::new(0, 1).recip();
     }
 
+    #[test]
+    fn test_mul_add() {
+        use num_traits::{MulAdd, MulAddAssign};
+
+        assert_eq!(_1_2.mul_add(_3_2, _1_3), Ratio::new(13, 12));
+        assert_eq!(_NEG1_2.mul_add(_1_2, _1_2), Ratio::new(1, 4));
+        assert_eq!(_0.mul_add(_3_2, _2), _2);
+        let mut x = _1_2;
+        x.mul_add_assign(_3_2, _1_3);
+        assert_eq!(x, Ratio::new(13, 12));
+
+        // differential check against the separate operators
+        fn next(state: &mut u64) -> u64 {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            *state
+        }
+        let mut state = 0xfedc_ba98_7654_3210_u64;
+        let mut gen = |state: &mut u64| {
+            let numer = (next(state) % 2001) as i32 - 1000;
+            let denom = (next(state) % 1000) as i32 + 1;
+            Ratio::new(numer, denom)
+        };
+        for _ in 0..10_000 {
+            let a = gen(&mut state);
+            let b = gen(&mut state);
+            let c = gen(&mut state);
+            let expected = a * b + c;
+            assert_eq!(a.mul_add(b, c), expected, "{} {} {}", a, b, c);
+            assert_eq!(a.checked_mul_add(&b, &c), Some(expected));
+            #[cfg(feature = "num-bigint")]
+            {
+                let big = |r: Ratio<i32>| {
+                    BigRational::new(BigInt::from(*r.numer()), BigInt::from(*r.denom()))
+                };
+                assert_eq!(big(a).mul_add(big(b), big(c)), big(expected));
+            }
+        }
+
+        // The gcd extraction keeps intermediates small: the textbook
+        // numerator a.n*b.n*c.d and denominator a.d*b.d*c.d both overflow
+        // an i16 here, but the fused form never leaves lowest terms.
+        let a = Ratio::<i16>::new(3000, 7);
+        let b = Ratio::new(7, 3000);
+        let c = Ratio::new(1, 2);
+        assert_eq!(
+            3000i16.checked_mul(7).and_then(|x| x.checked_mul(2)),
+            None
+        );
+        assert_eq!(
+            7i16.checked_mul(3000).and_then(|x| x.checked_mul(2)),
+            None
+        );
+        assert_eq!(a.checked_mul_add(&b, &c), Some(Ratio::new(3, 2)));
+        assert_eq!(a.mul_add(b, c), Ratio::new(3, 2));
+    }
+
     #[test]
     fn test_pow() {
         fn test(r: Rational, e: i32, expected: Rational) {
@@ -2657,6 +5831,81 @@ mod test {
         test(_3_2, 3, Ratio::new(27, 8));
     }
 
+    #[test]
+    fn test_pow_64() {
+        assert_eq!(_1_2.pow_u64(2), Ratio::new(1, 4));
+        assert_eq!(_3_2.pow_u64(0), _1);
+        assert_eq!(_3_2.pow_u64(3), Ratio::new(27, 8));
+        assert_eq!(_NEG1_2.pow_u64(2), Ratio::new(1, 4));
+        assert_eq!(_NEG1_2.pow_u64(3), Ratio::new(-1, 8));
+        assert_eq!(_0.pow_u64(5), _0);
+        assert_eq!(_1.pow_u64(u64::MAX), _1);
+
+        assert_eq!(_1_2.pow_i64(-2), Ratio::new(4, 1));
+        assert_eq!(_3_2.pow_i64(0), _1);
+        assert_eq!(_3_2.pow_i64(-1), _3_2.recip());
+        assert_eq!(_3_2.pow_i64(3), Ratio::new(27, 8));
+        assert_eq!(_1.pow_i64(i64::MIN), _1);
+
+        #[cfg(feature = "num-bigint")]
+        {
+            let two_thirds = BigRational::new(BigInt::from(2), BigInt::from(3));
+            assert_eq!(
+                two_thirds.pow_u64(10),
+                BigRational::new(BigInt::from(1024), BigInt::from(59049))
+            );
+            assert_eq!(
+                two_thirds.pow_i64(-10),
+                BigRational::new(BigInt::from(59049), BigInt::from(1024))
+            );
+            // An exponent far outside `i32` range still works for `BigInt`.
+            let big = BigRational::from_integer(BigInt::from(2)).pow_u64(1 << 17);
+            assert_eq!(big, big.clone().pow_i64(-1).pow_i64(-1));
+        }
+    }
+
+    #[test]
+    fn test_pow_unsigned_base() {
+        // Negative exponents only need the reciprocal, not `Neg` on `T`.
+        assert_eq!(Ratio::<u8>::new(2, 3).pow(-2), Ratio::new(9u8, 4));
+        assert_eq!(Ratio::<u32>::new(2, 3).pow(-1), Ratio::new(3u32, 2));
+        assert_eq!(Ratio::<u8>::one().pow(i32::MIN), Ratio::one());
+        assert_eq!(Ratio::<u32>::new(2, 3).pow_i64(-2), Ratio::new(9u32, 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "zero cannot be raised to a negative power")]
+    fn test_pow_zero_base_negative_expon() {
+        let _ = Ratio::<u32>::zero().pow(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "zero cannot be raised to a negative power")]
+    fn test_pow_i64_zero_base_negative_expon() {
+        let _ = _0.pow_i64(-1);
+    }
+
+    #[test]
+    fn test_checked_pow() {
+        assert_eq!(_1_2.checked_pow(2), Some(Ratio::new(1, 4)));
+        assert_eq!(_1_2.checked_pow(-2), Some(Ratio::new(4, 1)));
+        assert_eq!(_3_2.checked_pow(0), Some(_1));
+        assert_eq!(_0.checked_pow(5), Some(_0));
+        assert_eq!(_0.checked_pow(0), Some(_1));
+        assert_eq!(_0.checked_pow(-1), None);
+        assert_eq!(_1.checked_pow(i32::MIN), Some(_1));
+        assert_eq!((-_1).checked_pow(i32::MIN), Some(_1));
+        assert_eq!(
+            Ratio::<u8>::new(2, 3).checked_pow(-2),
+            Some(Ratio::new(9u8, 4))
+        );
+        // Component overflow yields `None` instead of a panic.
+        assert_eq!(Ratio::<i8>::from_integer(3).checked_pow(6), None);
+        assert_eq!(Ratio::new(i32::MAX, 1).checked_pow(2), None);
+        // The reciprocal of a `T::MIN` power cannot be sign-normalized.
+        assert_eq!(Ratio::<i8>::from_integer(-128).checked_pow(-1), None);
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn test_to_from_str() {
@@ -2749,10 +5998,10 @@ mod test {
     fn test_signed() {
         assert_eq!(_NEG1_2.abs(), _1_2);
         assert_eq!(_3_2.abs_sub(&_1_2), _1);
-        assert_eq!(_1_2.abs_sub(&_3_2), Zero::zero());
-        assert_eq!(_1_2.signum(), One::one());
+        assert_eq!(_1_2.abs_sub(&_3_2), Ratio::zero());
+        assert_eq!(_1_2.signum(), Ratio::one());
         assert_eq!(_NEG1_2.signum(), -<Ratio<isize>>::one());
-        assert_eq!(_0.signum(), Zero::zero());
+        assert_eq!(_0.signum(), Ratio::zero());
         assert!(_NEG1_2.is_negative());
         assert!(_1_NEG2.is_negative());
         assert!(!_NEG1_2.is_positive());
@@ -2765,6 +6014,123 @@ mod test {
         assert!(!_0.is_negative());
     }
 
+    #[test]
+    fn test_signum_int() {
+        assert_eq!(_1_2.signum_int(), 1);
+        assert_eq!(_3_2.signum_int(), 1);
+        assert_eq!(_NEG1_2.signum_int(), -1);
+        assert_eq!(_1_NEG2.signum_int(), -1);
+        assert_eq!(_NEG1_NEG2.signum_int(), 1);
+        assert_eq!(_0.signum_int(), 0);
+        assert_eq!(Ratio::new_raw(0, -2).signum_int(), 0);
+    }
+
+    #[test]
+    fn test_mirrored_ops() {
+        // T on the left-hand side, by value and by reference
+        assert_eq!(5 + _1_2, Ratio::new(11, 2));
+        assert_eq!(5 + &_1_2, Ratio::new(11, 2));
+        assert_eq!(&5 + _1_2, Ratio::new(11, 2));
+        assert_eq!(&5 + &_1_2, Ratio::new(11, 2));
+
+        assert_eq!(5 - _1_2, Ratio::new(9, 2));
+        assert_eq!(&5 - &_1_2, Ratio::new(9, 2));
+        assert_eq!(5 * _1_2, _5_2);
+        assert_eq!(&5 * &_1_2, _5_2);
+        assert_eq!(5 / _1_2, Ratio::from_integer(10));
+        assert_eq!(&5 / &_1_2, Ratio::from_integer(10));
+        assert_eq!(5 % _3_2, _1_2);
+        assert_eq!(&5 % &_3_2, _1_2);
+
+        // Unsigned types get the same treatment
+        let half = Ratio::<u32>::new(1, 2);
+        assert_eq!(5u32 + half, Ratio::new(11, 2));
+        assert_eq!(5u32 - half, Ratio::new(9, 2));
+        assert_eq!(5u32 * half, Ratio::new(5, 2));
+        assert_eq!(5u32 / half, Ratio::from_integer(10));
+        assert_eq!(&5u32 % &Ratio::<u32>::new(3, 2), half);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mirrored_div_by_zero() {
+        let _ = 5 / _0;
+    }
+
+    #[test]
+    fn test_ratio_gcd_lcm() {
+        assert_eq!(Ratio::new(1, 6).gcd(&Ratio::new(1, 4)), Ratio::new(1, 12));
+        assert_eq!(Ratio::new(1, 6).lcm(&Ratio::new(1, 4)), Ratio::new(1, 2));
+        assert_eq!(Ratio::new(3, 4).gcd(&Ratio::new(5, 6)), Ratio::new(1, 12));
+        assert_eq!(Ratio::new(3, 4).lcm(&Ratio::new(5, 6)), Ratio::new(15, 2));
+
+        // gcd(0, x) == |x|, lcm with zero is zero
+        assert_eq!(_0.gcd(&_NEG1_2), _1_2);
+        assert_eq!(_NEG1_2.gcd(&_0), _1_2);
+        assert_eq!(_0.lcm(&_1_2), _0);
+        assert_eq!(_1_2.lcm(&_0), _0);
+
+        // signs are normalized away
+        assert_eq!(_NEG1_2.gcd(&_NEG1_3), Ratio::new(1, 6));
+        assert_eq!(_NEG1_2.lcm(&_NEG1_3), _1);
+
+        // these would overflow with naive cross-multiplication in i8
+        let a = Ratio::<i8>::new(1, 30);
+        let b = Ratio::<i8>::new(1, 20);
+        assert_eq!(a.gcd(&b), Ratio::new(1, 60));
+        assert_eq!(a.lcm(&b), Ratio::new(1, 10));
+    }
+
+    #[test]
+    fn test_euclid() {
+        use num_traits::Euclid;
+
+        fn test(a: Rational, b: Rational) {
+            let q = a.div_euclid(&b);
+            let r = a.rem_euclid(&b);
+            assert!(q.is_integer());
+            assert!(!r.is_negative());
+            assert!(r < b.abs());
+            assert_eq!(a, b * q + r);
+        }
+
+        test(_5_2, _1_2);
+        test(_5_2, _NEG1_2);
+        test(-_5_2, _1_2);
+        test(-_5_2, _NEG1_2);
+        test(_1_3, _2_3);
+        test(_NEG1_3, _2_3);
+
+        assert_eq!(_5_2.div_euclid(&_2_3), Ratio::from_integer(3));
+        assert_eq!(_5_2.rem_euclid(&_2_3), _1_2);
+        assert_eq!((-_5_2).div_euclid(&_2_3), Ratio::from_integer(-4));
+        assert_eq!((-_5_2).rem_euclid(&_2_3), Ratio::new(1, 6));
+    }
+
+    #[test]
+    fn test_div_rem() {
+        fn test(a: Rational, b: Rational) {
+            let (q, r) = a.div_rem(&b);
+            assert_eq!(Ratio::from_integer(q), (a / b).trunc());
+            assert_eq!(a, b * Ratio::from_integer(q) + r);
+
+            let (q, r) = a.div_mod_floor(&b);
+            assert_eq!(Ratio::from_integer(q), (a / b).floor());
+            assert_eq!(a, b * Ratio::from_integer(q) + r);
+        }
+
+        assert_eq!(Ratio::new(7, 2).div_rem(&_1), (3, _1_2));
+        assert_eq!(Ratio::new(7, 2).div_mod_floor(&_1), (3, _1_2));
+        assert_eq!(Ratio::new(-7, 2).div_rem(&_1), (-3, _NEG1_2));
+        assert_eq!(Ratio::new(-7, 2).div_mod_floor(&_1), (-4, _1_2));
+
+        test(_5_2, _1_3);
+        test(-_5_2, _1_3);
+        test(_5_2, _NEG1_3);
+        test(-_5_2, _NEG1_3);
+        test(_2, _1_2);
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn test_hash() {
@@ -2845,6 +6211,53 @@ mod test {
         assert_eq!(products[0], products[2]);
     }
 
+    #[test]
+    #[cfg(all(feature = "std", feature = "num-bigint"))]
+    fn ratio_iter_sum_coprime_denoms() {
+        use std::vec::Vec;
+
+        // All primes below `limit`, so the denominators are pairwise coprime
+        // and a naive left-to-right fold does quadratic gcd work.
+        fn primes(limit: usize) -> Vec<usize> {
+            let mut sieve = vec![true; limit];
+            (2..limit)
+                .filter(|&p| {
+                    if sieve[p] {
+                        for multiple in (p * p..limit).step_by(p) {
+                            sieve[multiple] = false;
+                        }
+                    }
+                    sieve[p]
+                })
+                .collect()
+        }
+
+        let nums: Vec<BigRational> = primes(10_000)
+            .into_iter()
+            .map(|p| Ratio::new(BigInt::one(), BigInt::from_usize(p).unwrap()))
+            .collect();
+
+        // The tree reduction must be observably identical to a plain fold.
+        let folded = nums[..200]
+            .iter()
+            .cloned()
+            .fold(BigRational::zero(), |sum, num| sum + num);
+        assert_eq!(nums[..200].iter().cloned().sum::<BigRational>(), folded);
+
+        // The full sum should complete quickly thanks to the balanced
+        // reduction; just sanity-check the value.
+        let sum: BigRational = nums.iter().cloned().sum();
+        assert!(sum > BigRational::from_integer(2.into()));
+        assert!(sum < BigRational::from_integer(3.into()));
+
+        let product: BigRational = nums[..200].iter().cloned().product();
+        let folded = nums[..200]
+            .iter()
+            .cloned()
+            .fold(BigRational::one(), |prod, num| prod * num);
+        assert_eq!(product, folded);
+    }
+
     #[test]
     fn test_num_zero() {
         let zero = Rational64::zero();
@@ -2,7 +2,7 @@ use crate::Ratio;
 
 use core::cmp;
 use num_integer::Integer;
-use num_traits::{One, Pow};
+use num_traits::{One, Pow, Zero};
 
 macro_rules! pow_unsigned_impl {
     (@ $exp:ty) => {
@@ -56,6 +56,9 @@ macro_rules! pow_signed_impl {
             match expon.sign() {
                 Sign::NoSign => One::one(),
                 Sign::Minus => {
+                    if self.is_zero() {
+                        panic!("zero cannot be raised to a negative power");
+                    }
                     Pow::pow(self, expon.magnitude()).into_recip()
                 }
                 Sign::Plus => Pow::pow(self, expon.magnitude()),
@@ -69,6 +72,11 @@ macro_rules! pow_signed_impl {
             match expon.cmp(&0) {
                 cmp::Ordering::Equal => One::one(),
                 cmp::Ordering::Less => {
+                    // The reciprocal is fine for unsigned `T` (it only swaps
+                    // the positive components), but not for a zero base.
+                    if self.is_zero() {
+                        panic!("zero cannot be raised to a negative power");
+                    }
                     let expon = expon.wrapping_abs() as $unsigned;
                     Pow::pow(self, expon).into_recip()
                 }
@@ -0,0 +1,63 @@
+//! Proptest strategies for generating well-formed ratios.
+
+use crate::Ratio;
+
+use core::fmt;
+use num_integer::Integer;
+use proptest::arbitrary::Arbitrary;
+use proptest::prelude::*;
+
+/// Creates a strategy for ratios built from the given numerator and
+/// denominator strategies, filtering out zero denominators.
+///
+/// The generated ratios are well-formed but not necessarily reduced; feed
+/// them through [`Ratio::reduced`] in properties that require the reduced
+/// invariant.
+pub fn ratio<T, N, D>(numer: N, denom: D) -> impl Strategy<Value = Ratio<T>>
+where
+    T: Clone + Integer + fmt::Debug,
+    N: Strategy<Value = T>,
+    D: Strategy<Value = T>,
+{
+    (
+        numer,
+        denom.prop_filter("denominator must be nonzero", |d| !d.is_zero()),
+    )
+        .prop_map(|(numer, denom)| Ratio::new_raw(numer, denom))
+}
+
+/// Creates a strategy over the whole range of `T`, equivalent to
+/// `ratio(any::<T>(), any::<T>())`.
+pub fn any_ratio<T>() -> impl Strategy<Value = Ratio<T>>
+where
+    T: Clone + Integer + fmt::Debug + Arbitrary,
+{
+    ratio(any::<T>(), any::<T>())
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod test {
+    use super::{any_ratio, ratio};
+    use crate::Ratio;
+    use core::cmp::Ordering;
+    use num_traits::Zero;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_any_ratio_is_well_formed(r in any_ratio::<i32>()) {
+            prop_assert!(!r.denom().is_zero());
+            prop_assert_eq!(r.cmp(&r), Ordering::Equal);
+            prop_assert_eq!(r.is_zero(), r.numer().is_zero());
+        }
+
+        #[test]
+        fn test_custom_ranges(r in ratio(-100..100i64, 1..50i64)) {
+            prop_assert!(*r.denom() >= 1);
+            let reduced = r.reduced();
+            prop_assert_eq!(reduced, r);
+            prop_assert!(*reduced.denom() > 0);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use crate::Ratio;
+
+use num_integer::Integer;
+use num_traits::CheckedMul;
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use rand::Rng;
+
+/// Uniform-in-value sampler for ranges of ratios, enabling
+/// `rng.gen_range(low, high)` for `Ratio<T>`.
+///
+/// Both bounds are brought to a common denominator, which is then refined by
+/// repeated doubling for as long as the scaled numerators stay representable
+/// in `T` (up to 64 doublings), and the numerator is sampled uniformly. The
+/// samples are therefore uniform in value over an evenly spaced grid covering
+/// the range, as fine as `T` allows -- not uniform over representations.
+#[derive(Clone, Debug)]
+pub struct UniformRatio<T: SampleUniform> {
+    numer: T::Sampler,
+    denom: T,
+}
+
+impl<T> SampleUniform for Ratio<T>
+where
+    T: Clone + Integer + CheckedMul + SampleUniform,
+{
+    type Sampler = UniformRatio<T>;
+}
+
+/// Scales `low` and `high` to a common denominator, refined by doubling
+/// while the numerators remain representable.
+fn scale_bounds<T>(low: Ratio<T>, high: Ratio<T>) -> (T, T, T)
+where
+    T: Clone + Integer + CheckedMul,
+{
+    let mut denom = low.denom().lcm(high.denom());
+    let mut low_numer = low.numer().clone() * (denom.clone() / low.denom().clone());
+    let mut high_numer = high.numer().clone() * (denom.clone() / high.denom().clone());
+    let two = T::one() + T::one();
+    for _ in 0..64 {
+        match (
+            low_numer.checked_mul(&two),
+            high_numer.checked_mul(&two),
+            denom.checked_mul(&two),
+        ) {
+            (Some(l), Some(h), Some(d)) => {
+                low_numer = l;
+                high_numer = h;
+                denom = d;
+            }
+            _ => break,
+        }
+    }
+    (low_numer, high_numer, denom)
+}
+
+impl<T> UniformSampler for UniformRatio<T>
+where
+    T: Clone + Integer + CheckedMul + SampleUniform,
+{
+    type X = Ratio<T>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let low = low.borrow().clone();
+        let high = high.borrow().clone();
+        assert!(low < high, "Uniform::new called with `low >= high`");
+        let (low_numer, high_numer, denom) = scale_bounds(low, high);
+        UniformRatio {
+            numer: T::Sampler::new(low_numer, high_numer),
+            denom,
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let low = low.borrow().clone();
+        let high = high.borrow().clone();
+        assert!(
+            low <= high,
+            "Uniform::new_inclusive called with `low > high`"
+        );
+        let (low_numer, high_numer, denom) = scale_bounds(low, high);
+        UniformRatio {
+            numer: T::Sampler::new_inclusive(low_numer, high_numer),
+            denom,
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Ratio<T> {
+        Ratio::new(self.numer.sample(rng), self.denom.clone())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod test {
+    use crate::Ratio;
+    use num_traits::{Signed, ToPrimitive};
+    use rand::distributions::uniform::UniformSampler;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::UniformRatio;
+
+    #[test]
+    fn test_bounds_and_distribution() {
+        let low = Ratio::new(1i64, 3);
+        let high = Ratio::new(2i64, 3);
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let mut buckets = [0u32; 10];
+        for _ in 0..10_000 {
+            let x: Ratio<i64> = rng.gen_range(low, high);
+            assert!(low <= x);
+            assert!(x < high);
+            // Map [1/3, 2/3) onto buckets 0..10; the sampled denominators
+            // are near i64::MAX, so bucket in floating point.
+            let bucket = ((x.to_f64().unwrap() - low.to_f64().unwrap()) * 30.0) as usize;
+            buckets[bucket.min(9)] += 1;
+        }
+        // Loose chi-squared-style check: each bucket should hold roughly
+        // 1000 of the 10_000 samples.
+        for &count in &buckets {
+            assert!(count > 700, "bucket too empty: {}", count);
+            assert!(count < 1300, "bucket too full: {}", count);
+        }
+    }
+
+    #[test]
+    fn test_small_types_stay_in_range() {
+        let low = Ratio::new(1i8, 3);
+        let high = Ratio::new(2i8, 3);
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..1000 {
+            let x: Ratio<i8> = rng.gen_range(low, high);
+            assert!(low <= x && x < high);
+        }
+    }
+
+    #[test]
+    fn test_negative_range() {
+        let low = Ratio::new(-3i32, 2);
+        let high = Ratio::new(-1i32, 7);
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut seen_below_minus_one = false;
+        for _ in 0..1000 {
+            let x: Ratio<i32> = rng.gen_range(low, high);
+            assert!(low <= x && x < high);
+            assert!(x.is_negative());
+            if x < Ratio::from_integer(-1) {
+                seen_below_minus_one = true;
+            }
+        }
+        assert!(seen_below_minus_one);
+    }
+
+    #[test]
+    fn test_inclusive() {
+        let half = Ratio::new(1i32, 2);
+        let sampler = UniformRatio::new_inclusive(half, half);
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..100 {
+            assert_eq!(sampler.sample(&mut rng), half);
+        }
+
+        let low = Ratio::new(0i32, 1);
+        let high = Ratio::new(1i32, 1);
+        let sampler = UniformRatio::new_inclusive(low, high);
+        for _ in 0..1000 {
+            let x = sampler.sample(&mut rng);
+            assert!(!x.is_negative());
+            assert!(x <= high);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "low >= high")]
+    fn test_empty_range() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let _: Ratio<i32> = rng.gen_range(Ratio::new(2, 3), Ratio::new(1, 3));
+    }
+
+    #[test]
+    fn test_zero_width_numerator_refinement() {
+        // A tiny range still yields in-bounds values after refinement.
+        let low = Ratio::new(0i64, 1);
+        let high = Ratio::new(1i64, 1_000_000);
+        let mut rng = StdRng::seed_from_u64(11);
+        for _ in 0..1000 {
+            let x: Ratio<i64> = rng.gen_range(low, high);
+            assert!(!x.is_negative() && x < high);
+            let _ = x.to_f64();
+        }
+    }
+}
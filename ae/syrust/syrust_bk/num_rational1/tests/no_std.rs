@@ -0,0 +1,19 @@
+//! Checks that the crate is usable from a `no_std` crate when the `std`
+//! feature is disabled: `cargo test --no-default-features --test no_std`.
+
+#![no_std]
+
+use core::str::FromStr;
+use num_rational::{Ratio, Rational64};
+
+#[test]
+fn ratio_works_without_std() {
+    let a = Ratio::new(6i64, 8);
+    assert_eq!(a, Ratio::new(3, 4));
+    assert_eq!(a + Ratio::new(1i64, 4), Ratio::from_integer(1));
+    assert_eq!(a.recip(), Ratio::new(4, 3));
+
+    let parsed = Rational64::from_str("-9/6").unwrap();
+    assert_eq!(parsed, Ratio::new(-3, 2));
+    assert_eq!(parsed.floor(), Ratio::from_integer(-2));
+}